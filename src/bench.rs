@@ -0,0 +1,196 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub(crate) fn new(value: &str) -> Result<Format, String> {
+        match value {
+            "table" => Ok(Format::Table),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!("invalid format '{}', expected table, json or csv", other))
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Settings {
+    pub(crate) warmup: usize,
+    pub(crate) iterations: usize,
+}
+
+pub(crate) struct Measurement {
+    samples: Vec<Duration>,
+}
+
+impl Measurement {
+    pub(crate) fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    pub(crate) fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    pub(crate) fn mean(&self) -> Duration {
+        self.samples.iter().sum::<Duration>() / self.samples.len().max(1) as u32
+    }
+
+    pub(crate) fn median(&self) -> Duration {
+        let sorted = self.samples.iter().sorted().collect_vec();
+        sorted.get(sorted.len() / 2).map(|d| **d).unwrap_or_default()
+    }
+}
+
+/// Runs `f` `warmup` times without recording and then `iterations` times measuring each run separately.
+pub(crate) fn measure<T>(settings: &Settings, mut f: impl FnMut() -> T) -> Measurement {
+    for _ in 0..settings.warmup {
+        black_box(f());
+    }
+    let samples = (0..settings.iterations)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect_vec();
+    Measurement { samples }
+}
+
+pub(crate) struct Timings {
+    pub(crate) parse: Measurement,
+    pub(crate) part1: Option<Measurement>,
+    pub(crate) part2: Option<Measurement>,
+}
+
+impl Timings {
+    fn phases(&self) -> Vec<(&str, &Measurement)> {
+        [("parse", Some(&self.parse)), ("part1", self.part1.as_ref()), ("part2", self.part2.as_ref())]
+            .into_iter()
+            .filter_map(|(name, measurement)| measurement.map(|m| (name, m)))
+            .collect_vec()
+    }
+
+    fn total(&self) -> Duration {
+        self.phases().iter().map(|(_, m)| m.median()).sum()
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+fn column(measurement: Option<&Measurement>) -> String {
+    measurement.map(|m| format!("{} ms", millis(m.median()))).unwrap_or("-".to_string())
+}
+
+fn table(results: &[(u8, Timings)]) -> String {
+    let mut lines = vec![format!("{:>3}  {:>14}  {:>14}  {:>14}  {:>14}", "Day", "Parse", "Part 1", "Part 2", "Total")];
+    for (day, timings) in results {
+        lines.push(format!("{:>3}  {:>14}  {:>14}  {:>14}  {:>14}",
+                           day,
+                           column(Some(&timings.parse)),
+                           column(timings.part1.as_ref()),
+                           column(timings.part2.as_ref()),
+                           format!("{} ms", millis(timings.total()))));
+    }
+    let total: Duration = results.iter().map(|(_, t)| t.total()).sum();
+    lines.push(format!("{:>3}  {:>14}  {:>14}  {:>14}  {:>14}", "All", "", "", "", format!("{} ms", millis(total))));
+    lines.push("(median of each phase)".to_string());
+    lines.join("\n")
+}
+
+fn rows(results: &[(u8, Timings)]) -> Vec<(u8, &str, &Measurement)> {
+    results.iter()
+        .flat_map(|(day, timings)| timings.phases().into_iter().map(move |(phase, m)| (*day, phase, m)))
+        .collect_vec()
+}
+
+fn json(results: &[(u8, Timings)]) -> String {
+    let entries = rows(results).iter()
+        .map(|(day, phase, m)| format!(
+            "  {{\"day\": {}, \"phase\": \"{}\", \"iterations\": {}, \"min_ms\": {}, \"median_ms\": {}, \"mean_ms\": {}, \"max_ms\": {}}}",
+            day, phase, m.samples.len(), millis(m.min()), millis(m.median()), millis(m.mean()), millis(m.max())))
+        .join(",\n");
+    format!("[\n{}\n]", entries)
+}
+
+fn csv(results: &[(u8, Timings)]) -> String {
+    let mut lines = vec!["day,phase,iterations,min_ms,median_ms,mean_ms,max_ms".to_string()];
+    for (day, phase, m) in rows(results) {
+        lines.push(format!("{},{},{},{},{},{},{}",
+                           day, phase, m.samples.len(), millis(m.min()), millis(m.median()), millis(m.mean()), millis(m.max())));
+    }
+    lines.join("\n")
+}
+
+pub(crate) fn render(results: &[(u8, Timings)], format: Format) -> String {
+    match format {
+        Format::Table => table(results),
+        Format::Json => json(results),
+        Format::Csv => csv(results),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(micros: &[u64]) -> Measurement {
+        Measurement { samples: micros.iter().map(|micros| Duration::from_micros(*micros)).collect_vec() }
+    }
+
+    fn results() -> Vec<(u8, Timings)> {
+        vec![
+            (1, Timings { parse: measurement(&[100, 300, 200]), part1: Some(measurement(&[1000])), part2: Some(measurement(&[2500, 1500])) }),
+            (2, Timings { parse: measurement(&[50]), part1: None, part2: Some(measurement(&[250])) }),
+        ]
+    }
+
+    #[test]
+    fn measure_runs_warmup_and_iterations() {
+        let mut calls = 0;
+        let measurement = measure(&Settings { warmup: 2, iterations: 3 }, || calls += 1);
+        assert_eq!(calls, 5);
+        assert_eq!(measurement.samples.len(), 3);
+        assert!(measurement.min() <= measurement.median() && measurement.median() <= measurement.max());
+    }
+
+    #[test]
+    fn statistics() {
+        let m = measurement(&[300, 100, 200, 1000]);
+        assert_eq!((m.min(), m.max()), (Duration::from_micros(100), Duration::from_micros(1000)));
+        assert_eq!((m.mean(), m.median()), (Duration::from_micros(400), Duration::from_micros(300)));
+    }
+
+    #[test]
+    fn render_table() {
+        assert_eq!(render(&results(), Format::Table), "\
+Day           Parse          Part 1          Part 2           Total
+  1        0.200 ms        1.000 ms        2.500 ms        3.700 ms
+  2        0.050 ms               -        0.250 ms        0.300 ms
+All                                                        4.000 ms
+(median of each phase)");
+    }
+
+    #[test]
+    fn render_json_and_csv() {
+        let json = render(&results(), Format::Json);
+        assert_eq!(json.lines().nth(1), Some("  {\"day\": 1, \"phase\": \"parse\", \"iterations\": 3, \"min_ms\": 0.100, \"median_ms\": 0.200, \"mean_ms\": 0.200, \"max_ms\": 0.300},"));
+        assert_eq!(json.lines().count(), 7);
+        assert_eq!(render(&results(), Format::Csv), "\
+day,phase,iterations,min_ms,median_ms,mean_ms,max_ms
+1,parse,3,0.100,0.200,0.200,0.300
+1,part1,1,1.000,1.000,1.000,1.000
+1,part2,2,1.500,2.500,2.000,2.500
+2,parse,1,0.050,0.050,0.050,0.050
+2,part2,1,0.250,0.250,0.250,0.250");
+    }
+}
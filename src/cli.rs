@@ -0,0 +1,424 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs::read_to_string;
+use std::io::{Read, stdin};
+use std::path::Path;
+use itertools::Itertools;
+use crate::bench::{Format, Settings};
+use crate::input::{Fetcher, Provider};
+
+pub(crate) const USAGE: &str = "\
+Usage: aoc [OPTIONS]
+
+Options:
+  -y, --year <YEAR>          event to run (default: the latest one with solutions)
+  -d, --day <DAY>            run a single day (can be repeated)
+      --days <DAYS>          run a list or range of days, e.g. 1-12 or 1,3,5-7
+  -p, --part <PART>          run only part 1, part 2 or both (default: both)
+  -i, --input [DAY=]<PATH>   read input from PATH instead of <DAY>.txt, '-' reads stdin
+      --set [DAY:]<K=V>      set parameter K of a day to V, e.g. 15:area=20 (can be repeated)
+      --report               print a visualisation after the answers of days that have one
+      --colour               use ANSI colours in reports
+      --verify               compare answers with the ones recorded in the answers manifest
+      --record               store answers missing from the manifest (implies --verify)
+      --answers <PATH>       answers manifest to use (default: answers.txt)
+      --cache <DIR>          read inputs from DIR/<YEAR>/<USER>/<DAY>.txt instead of <DAY>.txt
+      --user <NAME>          cache directory of the user the inputs belong to (default: default)
+      --session-file <PATH>  download inputs missing from the cache with the cookie in PATH (default: $AOC_SESSION)
+      --url <URL>            server to download inputs from (default: https://adventofcode.com)
+      --time                 measure parse, part 1 and part 2 run time instead of printing answers
+      --warmup <N>           untimed runs before measuring (default: 1)
+      --iterations <N>       timed runs of every phase (default: 5)
+      --format <FORMAT>      timing report and tabular reports as table, json or csv (default: table)
+  -h, --help                 print this message
+
+Without --day/--days every implemented day is run.";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Part {
+    First,
+    Second,
+    Both,
+}
+
+impl Part {
+    fn new(value: &str) -> Result<Part, String> {
+        match value {
+            "1" => Ok(Part::First),
+            "2" => Ok(Part::Second),
+            "both" | "all" => Ok(Part::Both),
+            other => Err(format!("invalid part '{}', expected 1, 2 or both", other))
+        }
+    }
+
+    pub(crate) fn first(&self) -> bool {
+        *self != Part::Second
+    }
+
+    pub(crate) fn second(&self) -> bool {
+        *self != Part::First
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum InputSource {
+    File(String),
+    Stdin,
+    Cache(Provider, u8),
+}
+
+impl InputSource {
+    fn new(path: &str) -> InputSource {
+        match path {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(path.to_string())
+        }
+    }
+
+    /// Names the input in the answers manifest, independent of the directory it is read from.
+    pub(crate) fn label(&self) -> String {
+        match self {
+            InputSource::File(path) => Path::new(path).file_name()
+                .map_or(path.clone(), |name| name.to_string_lossy().to_string()),
+            InputSource::Stdin => "-".to_string(),
+            InputSource::Cache(provider, day) => format!("{}/{}.txt", provider.user, day)
+        }
+    }
+
+    pub(crate) fn read(&self) -> Result<String, String> {
+        match self {
+            InputSource::File(path) => read_to_string(path)
+                .map_err(|e| format!("cannot read '{}': {}", path, e)),
+            InputSource::Stdin => {
+                let mut content = String::new();
+                stdin().read_to_string(&mut content)
+                    .map_err(|e| format!("cannot read stdin: {}", e))?;
+                Ok(content)
+            }
+            InputSource::Cache(provider, day) => provider.read(*day)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Options {
+    pub(crate) year: u16,
+    pub(crate) days: Vec<u8>,
+    pub(crate) part: Part,
+    pub(crate) help: bool,
+    pub(crate) verify: bool,
+    pub(crate) record: bool,
+    pub(crate) answers: String,
+    pub(crate) time: bool,
+    pub(crate) bench: Settings,
+    pub(crate) format: Format,
+    pub(crate) report: bool,
+    pub(crate) colour: bool,
+    /// Parameters to override as `(day, key, value)`.
+    pub(crate) parameters: Vec<(u8, String, String)>,
+    inputs: HashMap<u8, InputSource>,
+    default_input: Option<InputSource>,
+    provider: Option<Provider>,
+}
+
+fn parse_year(value: &str) -> Result<u16, String> {
+    match value.trim().parse() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!("invalid year '{}', expected 2015 or later", value))
+    }
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.trim().parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("invalid day '{}', expected a number between 1 and 25", value))
+    }
+}
+
+fn parse_days(value: &str) -> Result<Vec<u8>, String> {
+    let mut days = vec![];
+    for group in value.split(",") {
+        match group.split_once("-") {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!("invalid range '{}'", group));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(group)?)
+        }
+    }
+    Ok(days)
+}
+
+fn parse_count(option: &str, value: &str) -> Result<usize, String> {
+    value.parse()
+        .map_err(|_| format!("invalid value '{}' for {}, expected a number", value, option))
+}
+
+/// `[DAY:]KEY=VALUE` of `--set`.
+fn parse_setting(value: &str) -> Result<(Option<u8>, String, String), String> {
+    let (setting, assignment) = match value.split_once(":") {
+        Some((day, assignment)) => (Some(parse_day(day)?), assignment),
+        None => (None, value)
+    };
+    match assignment.split_once("=") {
+        Some((key, value)) if !key.is_empty() => Ok((setting, key.to_string(), value.to_string())),
+        _ => Err(format!("invalid setting '{}', expected [DAY:]KEY=VALUE", value))
+    }
+}
+
+fn expect_value(option: &str, value: Option<String>) -> Result<String, String> {
+    value.ok_or(format!("missing value for {}", option))
+}
+
+impl Options {
+    /// `available` lists the solved days of every registered year.
+    pub(crate) fn parse(args: impl Iterator<Item=String>, available: &[(u16, Vec<u8>)]) -> Result<Options, String> {
+        let mut args = args;
+        let mut year = None;
+        let mut days = vec![];
+        let mut part = Part::Both;
+        let mut help = false;
+        let mut verify = false;
+        let mut record = false;
+        let mut answers = "answers.txt".to_string();
+        let mut time = false;
+        let mut bench = Settings { warmup: 1, iterations: 5 };
+        let mut format = Format::Table;
+        let mut report = false;
+        let mut colour = false;
+        let mut settings = vec![];
+        let mut inputs = HashMap::new();
+        let mut default_input = None;
+        let mut cache = None;
+        let mut user = "default".to_string();
+        let mut session_file = None;
+        let mut url = "https://adventofcode.com".to_string();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "-y" | "--year" => year = Some(parse_year(&expect_value(&arg, args.next())?)?),
+                "-d" | "--day" => days.push(parse_day(&expect_value(&arg, args.next())?)?),
+                "--days" => days.extend(parse_days(&expect_value(&arg, args.next())?)?),
+                "-p" | "--part" => part = Part::new(&expect_value(&arg, args.next())?)?,
+                "-i" | "--input" => {
+                    let value = expect_value(&arg, args.next())?;
+                    match value.split_once("=") {
+                        Some((day, path)) => {
+                            inputs.insert(parse_day(day)?, InputSource::new(path));
+                        }
+                        None => default_input = Some(InputSource::new(&value))
+                    }
+                }
+                "--set" => settings.push(parse_setting(&expect_value(&arg, args.next())?)?),
+                "--report" => report = true,
+                "--colour" | "--color" => colour = true,
+                "--verify" => verify = true,
+                "--record" => record = true,
+                "--answers" => answers = expect_value(&arg, args.next())?,
+                "--cache" => cache = Some(expect_value(&arg, args.next())?),
+                "--user" => user = expect_value(&arg, args.next())?,
+                "--session-file" => session_file = Some(expect_value(&arg, args.next())?),
+                "--url" => url = expect_value(&arg, args.next())?,
+                "--time" => time = true,
+                "--warmup" => bench.warmup = parse_count(&arg, &expect_value(&arg, args.next())?)?,
+                "--iterations" => bench.iterations = parse_count(&arg, &expect_value(&arg, args.next())?)?,
+                "--format" => format = Format::new(&expect_value(&arg, args.next())?)?,
+                "-h" | "--help" => help = true,
+                other => return Err(format!("unknown argument '{}'", other))
+            }
+        }
+        let (year, available_days) = match year {
+            None => available.iter().max_by_key(|(year, _)| *year),
+            Some(year) => available.iter().find(|(y, _)| *y == year)
+        }.ok_or(format!("no solutions for {}, available years: {}",
+                        year.map(|y| y.to_string()).unwrap_or_default(),
+                        available.iter().map(|(year, _)| year).join(", ")))?;
+        if days.is_empty() {
+            days = available_days.to_vec();
+        }
+        let days = days.into_iter().unique().collect_vec();
+        if bench.iterations == 0 {
+            return Err("--iterations has to be at least 1".to_string());
+        }
+        if time && (verify || record) {
+            return Err("--time cannot be combined with --verify or --record".to_string());
+        }
+        if report && (time || verify || record) {
+            return Err("--report cannot be combined with --time, --verify or --record".to_string());
+        }
+        if default_input.is_some() && days.len() > 1 {
+            return Err("--input without DAY= can only be used when a single day is selected".to_string());
+        }
+        let mut parameters = vec![];
+        for (day, key, value) in settings {
+            match day {
+                Some(day) => parameters.push((day, key, value)),
+                None if days.len() == 1 => parameters.push((days[0], key, value)),
+                None => return Err("--set without DAY: can only be used when a single day is selected".to_string())
+            }
+        }
+        let stdin_readers = days.iter()
+            .filter(|day| inputs.get(day).or(default_input.as_ref()) == Some(&InputSource::Stdin))
+            .count();
+        if stdin_readers > 1 {
+            return Err("stdin can be used as input for only one day".to_string());
+        }
+        if session_file.is_some() && cache.is_none() {
+            return Err("--session-file requires --cache to store the downloaded inputs".to_string());
+        }
+        // The cookie is never taken from the command line, other local users can read it there.
+        let session = match session_file {
+            Some(path) => Some(read_to_string(&path)
+                .map_err(|e| format!("cannot read session file '{}': {}", path, e))?
+                .trim()
+                .to_string()),
+            None => env::var("AOC_SESSION").ok()
+        };
+        let provider = cache.map(|dir| Provider {
+            dir,
+            year: *year,
+            user,
+            fetcher: session.map(|session| Fetcher { url, session }),
+        });
+        Ok(Options { year: *year, days, part, help, verify: verify || record, record, answers, time, bench, format, report, colour, parameters, inputs, default_input, provider })
+    }
+
+    pub(crate) fn input(&self, day: u8) -> InputSource {
+        self.inputs.get(&day)
+            .or(self.default_input.as_ref())
+            .cloned()
+            .unwrap_or_else(|| match &self.provider {
+                Some(provider) => InputSource::Cache(provider.clone(), day),
+                None => InputSource::File(format!("{}.txt", day))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn available() -> Vec<(u16, Vec<u8>)> {
+        vec![(2021, vec![1, 2]), (2022, (1..=25).collect())]
+    }
+
+    fn parse(args: &str) -> Result<Options, String> {
+        Options::parse(args.split_whitespace().map(String::from), &available())
+    }
+
+    #[test]
+    fn defaults_to_every_day_of_latest_year() {
+        let options = parse("").unwrap();
+        assert_eq!(options.year, 2022);
+        assert_eq!(options.days, (1..=25).collect_vec());
+        assert_eq!(options.part, Part::Both);
+        assert_eq!(options.input(7), InputSource::File("7.txt".to_string()));
+        let options = parse("--year 2021").unwrap();
+        assert_eq!(options.days, vec![1, 2]);
+    }
+
+    #[test]
+    fn day_lists_and_ranges() {
+        assert_eq!(parse("-d 7 --day 3 -d 7").unwrap().days, vec![7, 3]);
+        assert_eq!(parse("--days 1-3,5,7-8").unwrap().days, vec![1, 2, 3, 5, 7, 8]);
+        assert_eq!(parse_days("4-2"), Err("invalid range '4-2'".to_string()));
+        assert!(parse("--days 0-3").is_err());
+        assert!(parse("--days 24-26").is_err());
+        assert!(parse("--day x").is_err());
+        assert!(parse("--year 2014").is_err());
+        assert!(parse("--year 2016").is_err());
+        assert!(Options::parse(std::iter::empty(), &[]).is_err());
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!(parse("-p 1").unwrap().part, Part::First);
+        assert_eq!(parse("--part 2").unwrap().part, Part::Second);
+        assert_eq!(parse("--part all").unwrap().part, Part::Both);
+        assert!(parse("--part 3").is_err());
+    }
+
+    #[test]
+    fn inputs_per_day() {
+        let options = parse("--days 1-3 -i 2=two.txt --input 3=-").unwrap();
+        assert_eq!(options.input(1), InputSource::File("1.txt".to_string()));
+        assert_eq!(options.input(2), InputSource::File("two.txt".to_string()));
+        assert_eq!(options.input(3), InputSource::Stdin);
+        assert_eq!(parse("-d 4 -i other.txt").unwrap().input(4), InputSource::File("other.txt".to_string()));
+        assert!(parse("--days 1-2 -i other.txt").is_err());
+        assert!(parse("--days 1-2 -i -").is_err());
+        assert!(parse("--input 26=x.txt").is_err());
+    }
+
+    #[test]
+    fn input_labels_ignore_directories() {
+        let options = parse("-d 7 -i /tmp/inputs/7.txt").unwrap();
+        assert_eq!(options.input(7).label(), "7.txt");
+        assert_eq!(InputSource::Stdin.label(), "-");
+        let options = parse("-d 7 --cache /var/cache/aoc --user alice").unwrap();
+        assert_eq!(options.input(7).label(), "alice/7.txt");
+        assert_eq!(parse("-d 7 --cache elsewhere --user alice").unwrap().input(7).label(), "alice/7.txt");
+    }
+
+    #[test]
+    fn settings() {
+        assert_eq!(parse_setting("15:area=20"), Ok((Some(15), "area".to_string(), "20".to_string())));
+        assert_eq!(parse_setting("start=A=B"), Ok((None, "start".to_string(), "A=B".to_string())));
+        assert!(parse_setting("=3").is_err());
+        assert!(parse_setting("15:area").is_err());
+        assert!(parse_setting("30:area=1").is_err());
+        let options = parse("-d 15 --set row=10 --set 17:rocks=5").unwrap();
+        assert_eq!(options.parameters, vec![(15, "row".to_string(), "10".to_string()), (17, "rocks".to_string(), "5".to_string())]);
+        assert!(parse("--days 15-16 --set row=10").is_err());
+    }
+
+    #[test]
+    fn record_implies_verify() {
+        let options = parse("--record").unwrap();
+        assert!(options.verify && options.record);
+        let options = parse("--verify --answers other.txt").unwrap();
+        assert!(options.verify && !options.record);
+        assert_eq!(options.answers, "other.txt");
+    }
+
+    #[test]
+    fn conflicting_modes() {
+        assert!(parse("--time --verify").is_err());
+        assert!(parse("--time --record").is_err());
+        assert!(parse("--report --time").is_err());
+        assert!(parse("--report --verify").is_err());
+        assert!(parse("--session-file cookie.txt").is_err());
+    }
+
+    #[test]
+    fn session_is_read_from_a_file() {
+        let path = env::temp_dir().join(format!("aoc-session-{}", std::process::id()));
+        std::fs::write(&path, "secret\n").unwrap();
+        let options = parse(&format!("--cache inputs --session-file {}", path.display())).unwrap();
+        assert_eq!(options.provider.unwrap().fetcher.unwrap().session, "secret");
+        std::fs::remove_file(&path).unwrap();
+        assert!(parse(&format!("--cache inputs --session-file {}", path.display())).is_err());
+        assert_eq!(parse("--cache inputs --session secret").unwrap_err(), "unknown argument '--session'");
+    }
+
+    #[test]
+    fn timing_settings() {
+        let options = parse("--time --warmup 0 --iterations 9 --format json").unwrap();
+        assert!(options.time);
+        assert_eq!((options.bench.warmup, options.bench.iterations, options.format), (0, 9, Format::Json));
+        assert!(parse("--iterations many").is_err());
+        assert_eq!(parse("--time --iterations 0").unwrap_err(), "--iterations has to be at least 1");
+        assert!(parse("--format xml").is_err());
+        let options = parse("--report --format csv").unwrap();
+        assert_eq!((options.report, options.format), (true, Format::Csv));
+    }
+
+    #[test]
+    fn malformed_arguments() {
+        assert_eq!(parse("--frobnicate").unwrap_err(), "unknown argument '--frobnicate'");
+        assert_eq!(parse("--day").unwrap_err(), "missing value for --day");
+        assert!(parse("-h").unwrap().help);
+    }
+}
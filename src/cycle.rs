@@ -0,0 +1,92 @@
+// Finding where a simulation starts repeating itself, so its value after a huge number of steps can be extrapolated.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// From step `start` on the simulation repeats itself every `length` steps.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Cycle {
+    pub(crate) start: usize,
+    pub(crate) length: usize,
+}
+
+/// Values observed after every step of a simulation, `values[0]` being the one before the first step.
+pub(crate) struct History<V> {
+    pub(crate) values: Vec<V>,
+    pub(crate) cycle: Option<Cycle>,
+}
+
+/// Runs `step` on `state` until the state `key` of a step was already seen, or `limit` steps were made.
+/// The key has to capture everything that influences the following steps, otherwise the cycle is a guess.
+pub(crate) fn detect<S, K: Eq + Hash, V>(
+    state: &mut S,
+    limit: usize,
+    mut step: impl FnMut(&mut S),
+    key: impl Fn(&S) -> K,
+    value: impl Fn(&S) -> V,
+) -> History<V> {
+    let mut seen = HashMap::new();
+    let mut values = vec![value(state)];
+    seen.insert(key(state), 0);
+    for steps in 1..=limit {
+        step(state);
+        values.push(value(state));
+        if let Some(start) = seen.insert(key(state), steps) {
+            return History { values, cycle: Some(Cycle { start, length: steps - start }) };
+        }
+    }
+    History { values, cycle: None }
+}
+
+impl History<i64> {
+    /// Value after `steps` steps, assuming every pass through the cycle changes the value by the same amount.
+    /// `None` when `steps` lies beyond the simulated steps and no cycle was found.
+    pub(crate) fn value_at(&self, steps: usize) -> Option<i64> {
+        if let Some(value) = self.values.get(steps) {
+            return Some(*value);
+        }
+        let Cycle { start, length } = self.cycle?;
+        let (cycles, rest) = ((steps - start) / length, (steps - start) % length);
+        let growth = self.values[start + length] - self.values[start];
+        Some(self.values[start + rest] + cycles as i64 * growth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_cycle_after_prefix() {
+        // 0, 1, 2, 3, 4, 2, 3, 4, ...
+        let mut state = 0;
+        let history = detect(&mut state, 100, |n| *n = if *n == 4 { 2 } else { *n + 1 }, |n| *n, |n| *n as i64);
+        assert_eq!(history.cycle, Some(Cycle { start: 2, length: 3 }));
+        assert_eq!(history.value_at(3), Some(3));
+        assert_eq!(history.value_at(1000), Some(4));
+        assert_eq!(history.value_at(1001), Some(2));
+    }
+
+    #[test]
+    fn extrapolates_growing_values() {
+        // The key repeats every 4 steps while the value keeps growing by 10 each time.
+        let mut state = (0, 0);
+        let history = detect(&mut state, 100, |(phase, total)| {
+            *total += [1, 2, 3, 4][*phase];
+            *phase = (*phase + 1) % 4;
+        }, |(phase, _)| *phase, |(_, total)| *total as i64);
+        assert_eq!(history.cycle, Some(Cycle { start: 0, length: 4 }));
+        assert_eq!(history.value_at(4), Some(10));
+        assert_eq!(history.value_at(401), Some(1001));
+        assert_eq!(history.value_at(402), Some(1003));
+    }
+
+    #[test]
+    fn stops_at_limit() {
+        let mut state = 0;
+        let history = detect(&mut state, 5, |n| *n += 1, |n| *n, |n| *n as i64);
+        assert_eq!(history.cycle, None);
+        assert_eq!(history.value_at(5), Some(5));
+        assert_eq!(history.value_at(6), None);
+    }
+}
@@ -0,0 +1,269 @@
+// Points on the map and in space, and the four directions of movement on a map where y grows downward.
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Point on a plane, `y` grows downwards like rows of a map.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Debug)]
+pub(crate) struct Point {
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+}
+
+impl Point {
+    pub(crate) const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub(crate) const fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub(crate) fn manhattan(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub(crate) fn chebyshev(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub(crate) fn signum(&self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    pub(crate) fn neighbours4(&self) -> [Point; 4] {
+        Direction::ALL.map(|direction| *self + direction.vector())
+    }
+
+    pub(crate) fn neighbours8(&self) -> [Point; 8] {
+        [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)]
+            .map(|(x, y)| *self + Point::new(x, y))
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Debug)]
+pub(crate) struct Point3 {
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+    pub(crate) z: i32,
+}
+
+impl Point3 {
+    pub(crate) const fn new(x: i32, y: i32, z: i32) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub(crate) fn dot(&self, other: &Point3) -> i32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The 6 points sharing a face with this one.
+    pub(crate) fn neighbours6(&self) -> [Point3; 6] {
+        [(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)]
+            .map(|(x, y, z)| *self + Point3::new(x, y, z))
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<i32> for Point3 {
+    type Output = Point3;
+
+    fn mul(self, factor: i32) -> Point3 {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise, starting from north.
+    pub(crate) const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    pub(crate) fn vector(&self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    pub(crate) fn turn_left(&self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub(crate) fn turn_right(&self) -> Direction {
+        self.turn_left().reverse()
+    }
+
+    pub(crate) fn reverse(&self) -> Direction {
+        self.turn_left().turn_left()
+    }
+
+    /// Direction of a single orthogonal step from `from` to `to`.
+    pub(crate) fn between(from: Point, to: Point) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|direction| from + direction.vector() == to)
+    }
+
+    /// Inverse of `from_arrow`.
+    pub(crate) fn arrow(&self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+
+    /// `U`, `R`, `D` or `L`.
+    pub(crate) fn from_letter(symbol: char) -> Option<Direction> {
+        match symbol {
+            'U' => Some(Direction::North),
+            'R' => Some(Direction::East),
+            'D' => Some(Direction::South),
+            'L' => Some(Direction::West),
+            _ => None
+        }
+    }
+
+    /// `^`, `>`, `v` or `<`.
+    pub(crate) fn from_arrow(symbol: char) -> Option<Direction> {
+        match symbol {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+        assert_eq!(a + b, Point::new(2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!((a - b).signum(), Point::new(1, -1));
+        assert_eq!(Point3::new(1, 2, 3) + Point3::new(1, 1, 1), Point3::new(2, 3, 4));
+        assert_eq!(-Point3::new(1, 2, 3) * 2, Point3::new(-2, -4, -6));
+        assert_eq!(Point3::new(1, 2, 3).dot(&Point3::new(4, -5, 6)), 12);
+    }
+
+    #[test]
+    fn distances() {
+        let a = Point::new(3, -2);
+        let b = Point::new(-1, 4);
+        assert_eq!(a.manhattan(&b), 10);
+        assert_eq!(a.chebyshev(&b), 6);
+    }
+
+    #[test]
+    fn turning() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+    }
+
+    #[test]
+    fn parsing() {
+        assert_eq!(Direction::from_letter('U'), Some(Direction::North));
+        assert_eq!(Direction::from_arrow('v'), Some(Direction::South));
+        assert_eq!(Direction::from_arrow('x'), None);
+        assert!(Direction::ALL.iter().all(|d| Direction::from_arrow(d.arrow()) == Some(*d)));
+        assert_eq!(Direction::between(Point::new(1, 1), Point::new(1, 0)), Some(Direction::North));
+        assert_eq!(Direction::between(Point::new(1, 1), Point::new(2, 2)), None);
+    }
+
+    #[test]
+    fn neighbours() {
+        let point = Point::new(5, 5);
+        assert!(point.neighbours4().iter().all(|n| n.manhattan(&point) == 1));
+        assert!(point.neighbours8().iter().all(|n| n.chebyshev(&point) == 1));
+        assert_eq!(Point3::new(0, 0, 0).neighbours6().iter().filter(|n| n.z == 0).count(), 4);
+    }
+}
@@ -0,0 +1,289 @@
+// Dense and sparse grids of cells addressed by `Point`, for the days working on character maps.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+use itertools::Itertools;
+use crate::geometry::Point;
+use crate::parse::{Expected, lines, ParseError};
+
+/// Wraps `point` around an area of `size.x` columns and `size.y` rows starting at the origin.
+pub(crate) fn wrap(point: Point, size: Point) -> Point {
+    Point::new(point.x.rem_euclid(size.x), point.y.rem_euclid(size.y))
+}
+
+/// Dense rectangular grid stored row by row, `x` is the column and `y` the row of a cell.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl Grid<char> {
+    /// Parses a character map, rows shorter than the longest one are padded with spaces.
+    /// Every character, including the padding, has to pass `valid`.
+    pub(crate) fn parse(content: &str, valid: impl Fn(char) -> bool, expected: Expected) -> Result<Grid<char>, ParseError> {
+        let width = lines(content).map(|line| line.chars().count()).max().unwrap_or(0);
+        let mut rows = vec![];
+        for (index, line) in lines(content).enumerate() {
+            let error = |token: &str| ParseError::new(line, token, expected.clone()).shifted(index);
+            if let Some((start, c)) = line.char_indices().find(|(_, c)| !valid(*c)) {
+                return Err(error(&line[start..start + c.len_utf8()]));
+            }
+            let mut row = line.chars().collect_vec();
+            if row.len() < width && !valid(' ') {
+                return Err(error(&line[line.len()..]));
+            }
+            row.resize(width, ' ');
+            rows.push(row);
+        }
+        Ok(Grid::from_rows(rows))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows of equal length.
+    pub(crate) fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map(|row| row.len()).unwrap_or(0);
+        assert!(rows.iter().all(|row| row.len() == width), "rows of a grid have to be of equal length");
+        Grid { width, height, cells: rows.into_iter().flatten().collect_vec() }
+    }
+
+    pub(crate) fn width(&self) -> usize {
+        self.width
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+
+    pub(crate) fn contains(&self, point: Point) -> bool {
+        point.x >= 0 && point.y >= 0 && (point.x as usize) < self.width && (point.y as usize) < self.height
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point).then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    pub(crate) fn get(&self, position: Point) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub(crate) fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    pub(crate) fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub(crate) fn positions(&self) -> impl Iterator<Item=Point> {
+        let width = self.width as i32;
+        (0..self.height as i32).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub(crate) fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, value)| predicate(value)).map(|(position, _)| position)
+    }
+
+    /// The up to 4 orthogonal neighbours of `position` that lie on the grid.
+    pub(crate) fn neighbours4(&self, position: Point) -> impl Iterator<Item=Point> + '_ {
+        position.neighbours4()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours of `position` that lie on the grid.
+    /// Day 23 has the same need on an unbounded grid and uses `SparseGrid::neighbours8`.
+    #[allow(dead_code)]
+    pub(crate) fn neighbours8(&self, position: Point) -> impl Iterator<Item=Point> + '_ {
+        position.neighbours8()
+            .into_iter()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+
+    /// Cells visited when walking from `position` in steps of `direction` until the edge, `position` excluded.
+    pub(crate) fn ray(&self, position: Point, direction: Point) -> impl Iterator<Item=(Point, &T)> + '_ {
+        (1..)
+            .map(move |step| position + direction * step)
+            .map_while(|position| self.get(position).map(|value| (position, value)))
+    }
+
+    /// Maps `position` back onto the grid as if its opposite edges were glued together.
+    pub(crate) fn wrap(&self, position: Point) -> Point {
+        wrap(position, Point::new(self.width as i32, self.height as i32))
+    }
+
+    pub(crate) fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect_vec() }
+    }
+
+    /// The grid row by row, `symbol` draws a single cell.
+    pub(crate) fn render(&self, symbol: impl Fn(&T) -> char) -> String {
+        (0..self.height)
+            .map(|row| self.row(row).iter().map(&symbol).collect::<String>())
+            .join("\n")
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|c| *c))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        self.get_mut(position).unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+/// Unbounded grid that only stores the occupied cells, for boards that grow or are mostly empty.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> SparseGrid<T> {
+    pub(crate) fn new() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new() }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub(crate) fn contains(&self, position: Point) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub(crate) fn get(&self, position: Point) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub(crate) fn insert(&mut self, position: Point, value: T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    pub(crate) fn remove(&mut self, position: Point) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    pub(crate) fn positions(&self) -> impl Iterator<Item=Point> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item=(Point, &T)> {
+        self.cells.iter().map(|(position, value)| (*position, value))
+    }
+
+    /// The occupied cells among the 8 orthogonal and diagonal neighbours of `position`.
+    pub(crate) fn neighbours8(&self, position: Point) -> impl Iterator<Item=(Point, &T)> + '_ {
+        position.neighbours8()
+            .into_iter()
+            .filter_map(|neighbour| self.get(neighbour).map(|value| (neighbour, value)))
+    }
+
+    /// Corners of the smallest rectangle holding every occupied cell as `(top_left, bottom_right)`.
+    pub(crate) fn bounds(&self) -> Option<(Point, Point)> {
+        let (min_x, max_x) = self.cells.keys().map(|point| point.x).minmax().into_option()?;
+        let (min_y, max_y) = self.cells.keys().map(|point| point.y).minmax().into_option()?;
+        Some((Point::new(min_x, min_y), Point::new(max_x, max_y)))
+    }
+
+    /// Renders the area between `top_left` and `bottom_right` inclusive, `empty` marks unoccupied cells.
+    pub(crate) fn render(&self, (top_left, bottom_right): (Point, Point), empty: char, symbol: impl Fn(&T) -> char) -> String {
+        (top_left.y..=bottom_right.y)
+            .map(|y| (top_left.x..=bottom_right.x)
+                .map(|x| self.get(Point::new(x, y)).map(&symbol).unwrap_or(empty))
+                .collect::<String>())
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item=(Point, T)>>(iter: I) -> SparseGrid<T> {
+        SparseGrid { cells: iter.into_iter().collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Grid<char> {
+        Grid::parse("ab\ncd\nef", |c| c.is_ascii_lowercase(), Expected::Format("a letter".to_string())).unwrap()
+    }
+
+    fn p(x: i32, y: i32) -> Point {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = example();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[p(0, 1)], 'c');
+        assert_eq!(grid.get(p(0, 3)), None);
+        assert_eq!(grid.get(p(-1, 0)), None);
+        assert_eq!(grid.find(|c| *c == 'f'), Some(p(1, 2)));
+    }
+
+    #[test]
+    fn parse_pads_short_rows() {
+        let grid = Grid::parse("  .#\n.\n#..", |c| matches!(c, ' ' | '.' | '#'), Expected::OneOf("' ', '.' or '#'".to_string())).unwrap();
+        assert_eq!(grid.render(|c| *c), "  .#\n.   \n#.. ");
+        assert_eq!(grid.to_string(), "  .#\n.   \n#.. ");
+    }
+
+    #[test]
+    fn parse_reports_invalid_cells() {
+        let expected = Expected::Format("a letter".to_string());
+        let error = Grid::parse("ab\nc1", |c| c.is_ascii_lowercase(), expected.clone()).err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 2, "1"));
+        let error = Grid::parse("ab\nc", |c| c.is_ascii_lowercase(), expected).err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 2, ""));
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = example();
+        assert_eq!(grid.neighbours4(p(0, 0)).collect_vec(), vec![p(1, 0), p(0, 1)]);
+        assert_eq!(grid.neighbours8(p(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rays_and_wrapping() {
+        let grid = example();
+        assert_eq!(grid.ray(p(0, 0), p(0, 1)).map(|(_, c)| *c).collect::<String>(), "ce");
+        assert_eq!(grid.ray(p(1, 2), p(-1, -1)).map(|(position, _)| position).collect_vec(), vec![p(0, 1)]);
+        assert_eq!(grid.wrap(p(2, -1)), p(0, 2));
+        assert_eq!(wrap(p(-3, 5), p(4, 4)), p(1, 1));
+    }
+
+    #[test]
+    fn sparse_bounds_and_render() {
+        let mut grid: SparseGrid<char> = [(p(0, 0), '#'), (p(-1, 2), 'o')].into_iter().collect();
+        assert_eq!(grid.bounds(), Some((p(-1, 0), p(0, 2))));
+        assert_eq!(grid.render(grid.bounds().unwrap(), '.', |c| *c), ".#\n..\no.");
+        assert_eq!(grid.neighbours8(p(-1, 1)).map(|(position, c)| (position, *c)).collect_vec(), vec![(p(0, 0), '#'), (p(-1, 2), 'o')]);
+        assert_eq!(grid.remove(p(-1, 2)), Some('o'));
+        assert_eq!(grid.len(), 1);
+        assert_eq!(grid.iter().collect_vec(), vec![(p(0, 0), &'#')]);
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+}
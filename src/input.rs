@@ -0,0 +1,250 @@
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+const USER_AGENT: &str = "aoc-runner (input cache)";
+
+/// Downloads puzzle inputs using the session cookie of a logged-in user.
+///
+/// Plain `http://` URLs are fetched directly, which is enough for a local mock server.
+/// `https://` URLs are handed over to `curl`.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Fetcher {
+    pub(crate) url: String,
+    pub(crate) session: String,
+}
+
+fn split_url(url: &str) -> Result<(&str, &str), String> {
+    let rest = url.strip_prefix("http://")
+        .ok_or(format!("unsupported url '{}'", url))?;
+    Ok(match rest.find("/") {
+        Some(index) => (&rest[..index], &rest[index..]),
+        None => (rest, "")
+    })
+}
+
+/// Host and port of the `host[:port]` part of a url, IPv6 addresses are written in brackets.
+fn split_host(authority: &str) -> Result<(&str, u16), String> {
+    let (host, port) = match authority.rsplit_once(":") {
+        Some((host, port)) if !port.contains("]") => {
+            (host, port.parse().map_err(|_| format!("invalid port '{}'", port))?)
+        }
+        _ => (authority, 80)
+    };
+    Ok((host.trim_start_matches("[").trim_end_matches("]"), port))
+}
+
+fn dechunk(body: &str) -> Result<String, String> {
+    let mut result = String::new();
+    let mut rest = body;
+    loop {
+        let (size, tail) = rest.split_once("\r\n").ok_or("truncated chunked response")?;
+        let size = usize::from_str_radix(size.trim(), 16)
+            .map_err(|_| format!("invalid chunk size '{}'", size))?;
+        if size == 0 {
+            return Ok(result);
+        }
+        let chunk = tail.get(..size).ok_or("truncated chunked response")?;
+        result.push_str(chunk);
+        rest = tail[size..].trim_start_matches("\r\n");
+    }
+}
+
+impl Fetcher {
+    fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.url.trim_end_matches("/"), year, day)
+    }
+
+    pub(crate) fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        if self.session.contains(['\r', '\n']) {
+            return Err("the session cookie cannot contain line breaks".to_string());
+        }
+        let url = self.input_url(year, day);
+        if url.starts_with("https://") {
+            self.fetch_with_curl(&url)
+        } else {
+            self.fetch_http(&url)
+        }
+    }
+
+    fn fetch_http(&self, url: &str) -> Result<String, String> {
+        let (host, path) = split_url(url)?;
+        let mut stream = TcpStream::connect(split_host(host)?)
+            .map_err(|e| format!("cannot connect to '{}': {}", host, e))?;
+        let request = format!(
+            "GET {} HTTP/1.1\r\nHost: {}\r\nCookie: session={}\r\nUser-Agent: {}\r\nConnection: close\r\n\r\n",
+            path, host, self.session, USER_AGENT);
+        stream.write_all(request.as_bytes())
+            .map_err(|e| format!("cannot send request to '{}': {}", host, e))?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response)
+            .map_err(|e| format!("cannot read response from '{}': {}", host, e))?;
+        let (head, body) = response.split_once("\r\n\r\n")
+            .ok_or(format!("malformed response from '{}'", url))?;
+        let status = head.lines().next().unwrap_or("");
+        if status.split(" ").nth(1) != Some("200") {
+            return Err(format!("cannot download '{}': {}", url, status));
+        }
+        let chunked = head.lines()
+            .any(|line| line.to_lowercase().replace(" ", "") == "transfer-encoding:chunked");
+        if chunked {
+            dechunk(body)
+        } else {
+            Ok(body.to_string())
+        }
+    }
+
+    /// The session is passed as a config file on stdin, arguments can be read by every local user.
+    fn fetch_with_curl(&self, url: &str) -> Result<String, String> {
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--user-agent", USER_AGENT, "--config", "-", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("cannot run curl: {}", e))?;
+        let config = format!("cookie = \"session={}\"\n", self.session.replace('\\', "\\\\").replace('"', "\\\""));
+        child.stdin.take().unwrap().write_all(config.as_bytes())
+            .map_err(|e| format!("cannot pass the session to curl: {}", e))?;
+        let output = child.wait_with_output()
+            .map_err(|e| format!("cannot run curl: {}", e))?;
+        if !output.status.success() {
+            return Err(format!("cannot download '{}': {}", url, String::from_utf8_lossy(&output.stderr).trim()));
+        }
+        String::from_utf8(output.stdout).map_err(|_| format!("'{}' is not valid UTF-8", url))
+    }
+}
+
+/// Resolves inputs from `<dir>/<year>/<user>/<day>.txt`, downloading missing ones when a fetcher is configured.
+/// A file that is already in the cache is never downloaded again.
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) struct Provider {
+    pub(crate) dir: String,
+    pub(crate) year: u16,
+    pub(crate) user: String,
+    pub(crate) fetcher: Option<Fetcher>,
+}
+
+impl Provider {
+    pub(crate) fn path(&self, day: u8) -> PathBuf {
+        Path::new(&self.dir)
+            .join(self.year.to_string())
+            .join(&self.user)
+            .join(format!("{}.txt", day))
+    }
+
+    pub(crate) fn read(&self, day: u8) -> Result<String, String> {
+        let path = self.path(day);
+        if path.exists() {
+            return read_to_string(&path).map_err(|e| format!("cannot read '{}': {}", path.display(), e));
+        }
+        let fetcher = self.fetcher.as_ref()
+            .ok_or(format!("'{}' is not cached and no session is configured to download it", path.display()))?;
+        let content = fetcher.fetch(self.year, day)?;
+        self.store(&path, &content)?;
+        Ok(content)
+    }
+
+    fn store(&self, path: &Path, content: &str) -> Result<(), String> {
+        let error = |e: std::io::Error| format!("cannot write '{}': {}", path.display(), e);
+        create_dir_all(path.parent().unwrap()).map_err(error)?;
+        let partial = path.with_extension("part");
+        write(&partial, content).map_err(error)?;
+        rename(&partial, path).map_err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_dir_all;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+    use super::*;
+
+    /// Serves `body` to every request and returns the request lines it received once `requests` were handled.
+    fn mock_server(requests: usize, body: &'static str) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut received = vec![];
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let lines = reader.by_ref().lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect::<Vec<String>>();
+                received.push(lines.join("\n"));
+                let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", body.len(), body);
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+            received
+        });
+        (url, handle)
+    }
+
+    fn provider(name: &str, fetcher: Option<Fetcher>) -> Provider {
+        let dir = temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = remove_dir_all(&dir);
+        Provider { dir: dir.to_string_lossy().to_string(), year: 2022, user: "tester".to_string(), fetcher }
+    }
+
+    #[test]
+    fn downloads_once_and_reads_from_cache() {
+        let (url, server) = mock_server(1, "1000\n2000\n");
+        let provider = provider("fetch", Some(Fetcher { url, session: "secret".to_string() }));
+        assert_eq!(provider.read(1).unwrap(), "1000\n2000\n");
+        assert_eq!(provider.read(1).unwrap(), "1000\n2000\n");
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=secret"));
+        assert!(provider.path(1).ends_with("2022/tester/1.txt"));
+        remove_dir_all(&provider.dir).unwrap();
+    }
+
+    /// Curl is only needed for `https://` urls, machines without it skip the test.
+    #[test]
+    fn curl_gets_session_from_stdin() {
+        if Command::new("curl").arg("--version").output().is_err() {
+            eprintln!("curl is not installed, skipping");
+            return;
+        }
+        let (url, server) = mock_server(1, "42\n");
+        let fetcher = Fetcher { url: url.clone(), session: "se\"cret".to_string() };
+        assert_eq!(fetcher.fetch_with_curl(&format!("{}/2022/day/2/input", url)).unwrap(), "42\n");
+        let requests = server.join().unwrap();
+        assert!(requests[0].contains("Cookie: session=se\"cret"));
+    }
+
+    #[test]
+    fn missing_input_without_session() {
+        let provider = provider("offline", None);
+        assert!(provider.read(3).unwrap_err().contains("no session"));
+    }
+
+    #[test]
+    fn session_with_line_breaks_is_rejected() {
+        let fetcher = Fetcher { url: "http://127.0.0.1:9".to_string(), session: "secret\r\nX-Injected: 1".to_string() };
+        assert_eq!(fetcher.fetch(2022, 1).unwrap_err(), "the session cookie cannot contain line breaks");
+    }
+
+    #[test]
+    fn hosts_and_ports() {
+        assert_eq!(split_url("http://localhost:8080/2022").unwrap(), ("localhost:8080", "/2022"));
+        assert_eq!(split_host("localhost:8080").unwrap(), ("localhost", 8080));
+        assert_eq!(split_host("example.com").unwrap(), ("example.com", 80));
+        assert_eq!(split_host("[::1]:3000").unwrap(), ("::1", 3000));
+        assert_eq!(split_host("[::1]").unwrap(), ("::1", 80));
+        assert!(split_host("localhost:http").is_err());
+    }
+
+    #[test]
+    fn chunked_body() {
+        assert_eq!(dechunk("4\r\n1000\r\n3\r\n\n20\r\n0\r\n\r\n").unwrap(), "1000\n20");
+    }
+}
@@ -0,0 +1,184 @@
+// Closed integer intervals and sets of them, used for the section assignments and the sensor coverage.
+
+use std::cmp::{max, min};
+use itertools::Itertools;
+
+/// Closed range of integers, both `start` and `end` belong to it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct Interval {
+    pub(crate) start: i64,
+    pub(crate) end: i64,
+}
+
+impl Interval {
+    pub(crate) fn new(start: i64, end: i64) -> Interval {
+        assert!(start <= end, "interval {}..={} is empty", start, end);
+        Interval { start, end }
+    }
+
+    pub(crate) fn len(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    pub(crate) fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    pub(crate) fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub(crate) fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub(crate) fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let (start, end) = (max(self.start, other.start), min(self.end, other.end));
+        (start <= end).then_some(Interval { start, end })
+    }
+}
+
+/// Set of integers stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub(crate) struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub(crate) fn new() -> IntervalSet {
+        IntervalSet { intervals: vec![] }
+    }
+
+    pub(crate) fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub(crate) fn len(&self) -> i64 {
+        self.intervals.iter().map(|interval| interval.len()).sum()
+    }
+
+    pub(crate) fn contains(&self, value: i64) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end < value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(value))
+    }
+
+    pub(crate) fn insert(&mut self, interval: Interval) {
+        // Intervals touching the new one, including adjacent ones, are merged into it.
+        let first = self.intervals.partition_point(|other| other.end < interval.start - 1);
+        let last = self.intervals.partition_point(|other| other.start <= interval.end + 1);
+        let merged = self.intervals[first..last].iter()
+            .fold(interval, |acc, other| Interval { start: min(acc.start, other.start), end: max(acc.end, other.end) });
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub(crate) fn remove(&mut self, interval: Interval) {
+        let first = self.intervals.partition_point(|other| other.end < interval.start);
+        let last = self.intervals.partition_point(|other| other.start <= interval.end);
+        let rest = self.intervals[first..last].iter()
+            .flat_map(|other| [
+                (other.start < interval.start).then(|| Interval { start: other.start, end: interval.start - 1 }),
+                (other.end > interval.end).then(|| Interval { start: interval.end + 1, end: other.end }),
+            ])
+            .flatten()
+            .collect_vec();
+        self.intervals.splice(first..last, rest);
+    }
+
+    pub(crate) fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.insert(*interval);
+        }
+        result
+    }
+
+    pub(crate) fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals.iter()
+            .cartesian_product(other.intervals.iter())
+            .filter_map(|(a, b)| a.intersection(b))
+            .collect()
+    }
+
+    pub(crate) fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.remove(*interval);
+        }
+        result
+    }
+
+    /// The integers inside `bounds` that are not in the set, as maximal intervals.
+    pub(crate) fn gaps(&self, bounds: Interval) -> IntervalSet {
+        IntervalSet::from_iter([bounds]).difference(self)
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item=Interval>>(iter: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::new(2, 4);
+        assert_eq!(interval.len(), 3);
+        assert!(interval.overlaps(&Interval::new(4, 8)));
+        assert!(!interval.overlaps(&Interval::new(5, 8)));
+        assert!(Interval::new(2, 8).contains_interval(&Interval::new(3, 7)));
+        assert!(interval.contains(4) && !interval.contains(5));
+        assert_eq!(interval.intersection(&Interval::new(3, 9)), Some(Interval::new(3, 4)));
+        assert_eq!(interval.intersection(&Interval::new(5, 9)), None);
+    }
+
+    #[test]
+    fn insert_merges_overlapping_and_adjacent() {
+        let mut intervals = set(&[(1, 3), (10, 12), (5, 6)]);
+        assert_eq!(intervals, set(&[(1, 3), (5, 6), (10, 12)]));
+        intervals.insert(Interval::new(4, 4));
+        assert_eq!(intervals, set(&[(1, 6), (10, 12)]));
+        intervals.insert(Interval::new(0, 20));
+        assert_eq!(intervals.intervals(), &[Interval::new(0, 20)]);
+        assert_eq!(intervals.len(), 21);
+    }
+
+    #[test]
+    fn remove_splits_intervals() {
+        let mut intervals = set(&[(-2, 24)]);
+        intervals.remove(Interval::new(2, 2));
+        assert_eq!(intervals, set(&[(-2, 1), (3, 24)]));
+        assert_eq!(intervals.len(), 26);
+        intervals.remove(Interval::new(0, 30));
+        assert_eq!(intervals, set(&[(-2, -1)]));
+        assert!(intervals.contains(-1) && !intervals.contains(0) && !intervals.contains(-3));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12)]);
+        assert_eq!(a.union(&b), set(&[(0, 15)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12)]));
+        assert_eq!(a.difference(&b), set(&[(0, 2), (13, 15)]));
+        assert_eq!(a.gaps(Interval::new(-1, 20)), set(&[(-1, -1), (6, 9), (16, 20)]));
+        assert!(a.gaps(Interval::new(1, 4)).is_empty());
+        assert!(!a.is_empty() && IntervalSet::new().is_empty());
+    }
+}
@@ -1,51 +1,156 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
+use std::env;
+use std::process::exit;
+use itertools::Itertools;
+use crate::bench::{render, Timings};
+use crate::cli::{Options, USAGE};
+use crate::parse::ParseError;
+use crate::registry::{Year, years};
+use crate::solution::{Answers, Puzzle};
+use crate::verify::{check, Manifest, Status, Summary};
+
+mod bench;
+mod cli;
+mod cycle;
+mod geometry;
+mod grid;
+mod input;
+mod interval;
+mod parse;
+mod registry;
+mod search;
+mod select;
+mod solution;
+mod verify;
+mod y2022;
+
+fn print_answers(answers: &Answers) {
+    for answer in [&answers.part1, &answers.part2].into_iter().flatten() {
+        println!("{}", answer);
+    }
+}
+
+fn load<'a>(year: &'a Year, day: u8, options: &Options) -> Result<(&'a dyn Puzzle, String), String> {
+    let puzzle = year.puzzle(day)
+        .ok_or(format!("day {} of {} is not implemented", day, year.year))?;
+    Ok((puzzle, options.input(day).read()?))
+}
+
+fn solve(year: &Year, day: u8, options: &Options) -> Result<(Answers, Option<String>), String> {
+    let (puzzle, content) = load(year, day, options)?;
+    let error = |e: ParseError| e.with_day(day).to_string();
+    let answers = puzzle.run(&content, options.part).map_err(error)?;
+    let report = match options.report {
+        true => puzzle.report(&content, options.colour, options.format).map_err(error)?,
+        false => None
+    };
+    Ok((answers, report))
+}
+
+fn time(year: &Year, day: u8, options: &Options) -> Result<Timings, String> {
+    let (puzzle, content) = load(year, day, options)?;
+    puzzle.time(&content, options.part, &options.bench)
+        .map_err(|e| e.with_day(day).to_string())
+}
+
+fn time_all(year: &Year, options: &Options) -> bool {
+    let mut failed = false;
+    let mut results = vec![];
+    for &day in &options.days {
+        match time(year, day, options) {
+            Ok(timings) => results.push((day, timings)),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    println!("{}", render(&results, options.format));
+    failed
+}
+
+fn verify(year: u16, day: u8, input: &str, answers: &Answers, manifest: &mut Manifest, summary: &mut Summary, record: bool) {
+    for (part, answer) in [(1, &answers.part1), (2, &answers.part2)] {
+        if let Some(answer) = answer {
+            let status = check(manifest.get(year, day, input, part), answer);
+            summary.report(day, input, part, &status);
+            if record && status == Status::Missing {
+                manifest.insert(year, day, input, part, answer);
+            }
+        }
+    }
+}
+
+fn configure(year: &mut Year, options: &Options) -> Result<(), String> {
+    for (day, key, value) in &options.parameters {
+        let missing = format!("day {} of {} is not implemented", day, year.year);
+        year.puzzle_mut(*day)
+            .ok_or(missing)?
+            .configure(key, value)
+            .map_err(|e| format!("day {}: {}", day, e))?;
+    }
+    Ok(())
+}
 
 fn main() {
-    day1::solve();
-    day2::solve();
-    day3::solve();
-    day4::solve();
-    day5::solve();
-    day6::solve();
-    day7::solve();
-    day8::solve();
-    day9::solve();
-    day10::solve();
-    day11::solve();
-    day12::solve();
-    day13::solve();
-    day14::solve();
-    day15::solve();
-    day16::solve();
-    day17::solve();
-    day18::solve();
-    day19::solve();
-    day20::solve();
-    day22::solve();
-    day23::solve();
-    day24::solve();
-    day25::solve();
+    let mut years = years();
+    let available = years.iter().map(|year| (year.year, year.days())).collect_vec();
+    let options = Options::parse(env::args().skip(1), &available).unwrap_or_else(|e| {
+        eprintln!("error: {}\n\n{}", e, USAGE);
+        exit(2)
+    });
+    if options.help {
+        println!("{}", USAGE);
+        return;
+    }
+    let year = years.iter_mut().find(|year| year.year == options.year).unwrap();
+    configure(year, &options).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(2)
+    });
+    let year = &*year;
+    if options.time {
+        if time_all(year, &options) {
+            exit(1);
+        }
+        return;
+    }
+    let mut manifest = options.verify.then(|| Manifest::load(&options.answers).unwrap_or_else(|e| {
+        eprintln!("error: {}", e);
+        exit(2)
+    }));
+    let mut summary = Summary::default();
+    let mut failed = false;
+    for &day in &options.days {
+        if options.days.len() > 1 && !options.verify {
+            println!("Day {}", day);
+        }
+        match (solve(year, day, &options), manifest.as_mut()) {
+            (Ok((answers, _)), Some(manifest)) => {
+                verify(year.year, day, &options.input(day).label(), &answers, manifest, &mut summary, options.record)
+            }
+            (Ok((answers, report)), None) => {
+                print_answers(&answers);
+                if let Some(report) = report {
+                    println!("{}", report);
+                }
+            }
+            (Err(e), _) => {
+                eprintln!("error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    if options.verify {
+        println!("{} passed, {} failed, {} missing", summary.passed, summary.failed, summary.missing);
+        failed |= summary.failed > 0;
+    }
+    if let (true, Some(manifest)) = (options.record, &manifest) {
+        if let Err(e) = manifest.save() {
+            eprintln!("error: {}", e);
+            failed = true;
+        }
+    }
+    if failed {
+        exit(1);
+    }
 }
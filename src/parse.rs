@@ -0,0 +1,138 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Expected {
+    Number,
+    Separator(String),
+    OneOf(String),
+    Format(String),
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expected::Number => write!(f, "a number"),
+            Expected::Separator(separator) => write!(f, "{:?}", separator),
+            Expected::OneOf(options) => write!(f, "one of {}", options),
+            Expected::Format(format) => write!(f, "{}", format),
+        }
+    }
+}
+
+/// Location of a malformed fragment of the input together with what the parser expected to find there.
+/// Lines and columns are counted from 1, the day is filled in by the runner.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ParseError {
+    pub(crate) day: Option<u8>,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+    pub(crate) expected: Expected,
+    pub(crate) found: String,
+}
+
+fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let position = token.as_ptr() as usize;
+    if position >= start && position <= start + line.len() {
+        line[..position - start].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl ParseError {
+    /// Error for `token`, which has to be a slice of `line` for the column to be reported correctly.
+    pub(crate) fn new(line: &str, token: &str, expected: Expected) -> ParseError {
+        ParseError {
+            day: None,
+            line: 1,
+            column: column(line, token),
+            expected,
+            found: token.to_string(),
+        }
+    }
+
+    /// Moves the error down by `lines`, used when a fragment was parsed separately from the rest of the input.
+    pub(crate) fn shifted(self, lines: usize) -> ParseError {
+        ParseError { line: self.line + lines, ..self }
+    }
+
+    pub(crate) fn with_day(self, day: u8) -> ParseError {
+        ParseError { day: Some(day), ..self }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(f, "line {}, column {}: expected {}, found ", self.line, self.column, self.expected)?;
+        match self.found.as_str() {
+            "" => write!(f, "end of line"),
+            found => write!(f, "{:?}", found)
+        }
+    }
+}
+
+/// Brings the raw input into the form every parser expects: no byte order mark, `\n` line endings
+/// and no trailing newline, so files saved by any editor parse the same way.
+pub(crate) fn normalize(content: &str) -> String {
+    content.trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .trim_end_matches(['\n', '\r'])
+        .to_string()
+}
+
+pub(crate) fn lines(content: &str) -> impl Iterator<Item=&str> {
+    content.split("\n")
+}
+
+pub(crate) fn number<T: FromStr>(line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse()
+        .map_err(|_| ParseError::new(line, token, Expected::Number))
+}
+
+pub(crate) fn split_once<'a>(line: &str, token: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    token.split_once(separator)
+        .ok_or_else(|| ParseError::new(line, token, Expected::Separator(separator.to_string())))
+}
+
+pub(crate) fn parse_lines<T>(content: &str, parser: impl Fn(&str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    lines(content)
+        .enumerate()
+        .map(|(index, line)| parser(line).map_err(|e| e.shifted(index)))
+        .collect()
+}
+
+/// Parses groups of lines separated by an empty line, keeping the line numbers relative to the whole content.
+pub(crate) fn parse_blocks<T>(content: &str, parser: impl Fn(&str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    let mut offset = 0;
+    let mut result = vec![];
+    for block in content.split("\n\n") {
+        result.push(parser(block).map_err(|e| e.shifted(offset))?);
+        offset += lines(block).count() + 1;
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_line_endings() {
+        assert_eq!(normalize("1\r\n2\r\n\r\n3\r\n"), "1\n2\n\n3");
+        assert_eq!(normalize("\u{feff}abc\n\n"), "abc");
+        assert_eq!(normalize("  a\n  b  \n"), "  a\n  b  ");
+    }
+
+    #[test]
+    fn blocks_keep_line_numbers() {
+        let error = parse_blocks("1\n2\n\n3\nx", |block| parse_lines(block, |line| number::<i32>(line, line)))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (5, 1));
+    }
+}
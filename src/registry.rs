@@ -0,0 +1,34 @@
+use itertools::Itertools;
+use crate::solution::Puzzle;
+use crate::y2022;
+
+/// All solved days of a single event, registered by the module of that year.
+pub(crate) struct Year {
+    pub(crate) year: u16,
+    pub(crate) puzzles: Vec<(u8, Box<dyn Puzzle>)>,
+}
+
+impl Year {
+    pub(crate) fn days(&self) -> Vec<u8> {
+        self.puzzles.iter().map(|(day, _)| *day).collect_vec()
+    }
+
+    pub(crate) fn puzzle(&self, day: u8) -> Option<&dyn Puzzle> {
+        self.puzzles.iter()
+            .find(|(d, _)| *d == day)
+            .map(|(_, puzzle)| puzzle.as_ref())
+    }
+
+    pub(crate) fn puzzle_mut(&mut self, day: u8) -> Option<&mut (dyn Puzzle + 'static)> {
+        self.puzzles.iter_mut()
+            .find(|(d, _)| *d == day)
+            .map(|(_, puzzle)| puzzle.as_mut())
+    }
+}
+
+/// Every year the runner knows about, a new year only has to be added here.
+pub(crate) fn years() -> Vec<Year> {
+    vec![
+        y2022::year(),
+    ]
+}
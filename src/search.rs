@@ -0,0 +1,165 @@
+// Breadth-first and A* search over graphs that are only known through the neighbours of each node.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Graph given implicitly by the neighbours of each node, so huge or infinite state spaces never have to be built.
+pub(crate) trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// Nodes reachable from `node` in a single step, together with the cost of that step.
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, u64)>;
+}
+
+/// Outcome of a search: the cost of every reached node and the node it was first reached from.
+pub(crate) struct Search<N> {
+    reached: HashMap<N, (u64, Option<N>)>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    /// Cost of the first node satisfying the goal, if the search found one.
+    pub(crate) fn goal_cost(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    pub(crate) fn cost(&self, node: &N) -> Option<u64> {
+        self.reached.get(node).map(|(cost, _)| *cost)
+    }
+
+    pub(crate) fn contains(&self, node: &N) -> bool {
+        self.reached.contains_key(node)
+    }
+
+    pub(crate) fn reached(&self) -> impl Iterator<Item=(&N, u64)> {
+        self.reached.iter().map(|(node, (cost, _))| (node, *cost))
+    }
+
+    /// Nodes from one of the sources up to `node`, both included.
+    pub(crate) fn path(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut current = self.reached.get(node)?;
+        while let (_, Some(previous)) = current {
+            path.push(previous.clone());
+            current = &self.reached[previous];
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub(crate) fn goal_path(&self) -> Option<Vec<N>> {
+        self.goal.as_ref().and_then(|goal| self.path(goal))
+    }
+}
+
+/// Breadth-first search from all `sources` at once, every step costs 1 whatever the graph says.
+/// Stops at the first node satisfying `is_goal`, or explores everything reachable when none does.
+pub(crate) fn bfs<G: Graph>(graph: &G, sources: impl IntoIterator<Item=G::Node>, is_goal: impl Fn(&G::Node) -> bool) -> Search<G::Node> {
+    let mut reached = HashMap::new();
+    let mut queue = VecDeque::new();
+    for source in sources {
+        if !reached.contains_key(&source) {
+            reached.insert(source.clone(), (0, None));
+            queue.push_back(source);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let cost = reached[&node].0;
+        if is_goal(&node) {
+            return Search { reached, goal: Some(node) };
+        }
+        for (neighbour, _) in graph.neighbours(&node) {
+            if !reached.contains_key(&neighbour) {
+                reached.insert(neighbour.clone(), (cost + 1, Some(node.clone())));
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    Search { reached, goal: None }
+}
+
+/// A* search, `heuristic` must never overestimate the remaining cost to a goal for the result to be the cheapest.
+/// With a heuristic of 0 this is Dijkstra's algorithm.
+pub(crate) fn astar<G: Graph>(
+    graph: &G,
+    sources: impl IntoIterator<Item=G::Node>,
+    is_goal: impl Fn(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> u64,
+) -> Search<G::Node> where G::Node: Ord {
+    let mut reached = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    for source in sources {
+        if !reached.contains_key(&source) {
+            reached.insert(source.clone(), (0, None));
+            queue.push(Reverse((heuristic(&source), 0, source)));
+        }
+    }
+    while let Some(Reverse((_, cost, node))) = queue.pop() {
+        if cost > reached[&node].0 {
+            continue;
+        }
+        if is_goal(&node) {
+            return Search { reached, goal: Some(node) };
+        }
+        for (neighbour, step) in graph.neighbours(&node) {
+            let next = cost + step;
+            if reached.get(&neighbour).is_none_or(|(known, _)| next < *known) {
+                reached.insert(neighbour.clone(), (next, Some(node.clone())));
+                queue.push(Reverse((next + heuristic(&neighbour), next, neighbour)));
+            }
+        }
+    }
+    Search { reached, goal: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Numbers on a line, stepping right costs 1 and jumping to the double costs 3.
+    struct Line;
+
+    impl Graph for Line {
+        type Node = u64;
+
+        fn neighbours(&self, node: &u64) -> Vec<(u64, u64)> {
+            [(node + 1, 1), (node * 2, 3)].into_iter().filter(|(n, _)| *n <= 20).collect()
+        }
+    }
+
+    #[test]
+    fn bfs_counts_steps() {
+        let search = bfs(&Line, [1], |n| *n == 12);
+        assert_eq!(search.goal_cost(), Some(4));
+        assert_eq!(search.goal_path(), Some(vec![1, 2, 3, 6, 12]));
+    }
+
+    #[test]
+    fn bfs_without_goal_explores_everything() {
+        let search = bfs(&Line, [5, 3], |_| false);
+        assert_eq!(search.goal_cost(), None);
+        assert_eq!(search.reached().count(), 18);
+        assert_eq!(search.cost(&4), Some(1));
+        assert!(!search.contains(&2));
+    }
+
+    #[test]
+    fn astar_uses_step_costs() {
+        let search = astar(&Line, [1], |n| *n == 12, |_| 0);
+        assert_eq!(search.goal_cost(), Some(8));
+        assert_eq!(search.path(&12).unwrap().first(), Some(&1));
+    }
+
+    #[test]
+    fn heuristic_keeps_cheapest_cost() {
+        let search = astar(&Line, [1], |n| *n == 20, |n| 20u64.saturating_sub(*n).min(1));
+        assert_eq!(search.goal_cost(), astar(&Line, [1], |n| *n == 20, |_| 0).goal_cost());
+    }
+
+    #[test]
+    fn multiple_sources() {
+        let search = bfs(&Line, [1, 10], |n| *n == 12);
+        assert_eq!(search.goal_path(), Some(vec![10, 11, 12]));
+    }
+}
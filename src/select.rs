@@ -0,0 +1,83 @@
+// Picking the few largest items of a long sequence while holding only those few in memory.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// Item in the heap, ordered by its key and for equal keys by coming first.
+struct Keyed<K, T> {
+    key: K,
+    position: usize,
+    item: T,
+}
+
+impl<K: Ord, T> Keyed<K, T> {
+    fn rank(&self) -> (&K, Reverse<usize>) {
+        (&self.key, Reverse(self.position))
+    }
+}
+
+impl<K: Ord, T> PartialEq for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank() == other.rank()
+    }
+}
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialOrd for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
+/// The `k` items with the largest `key`, largest first, items that come first win ties.
+/// Only `k` items are kept at any time, so `items` can be far larger than what fits in memory.
+pub(crate) fn top_k_by_key<T, K: Ord>(items: impl IntoIterator<Item=T>, k: usize, key: impl Fn(&T) -> K) -> Vec<T> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for (position, item) in items.into_iter().enumerate() {
+        let candidate = Reverse(Keyed { key: key(&item), position, item });
+        if heap.len() < k {
+            heap.push(candidate);
+        } else if heap.peek().is_some_and(|smallest| candidate < *smallest) {
+            heap.pop();
+            heap.push(candidate);
+        }
+    }
+    heap.into_sorted_vec().into_iter().map(|Reverse(keyed)| keyed.item).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn top_k(items: impl IntoIterator<Item=u64>, k: usize) -> Vec<u64> {
+        top_k_by_key(items, k, |item| *item)
+    }
+
+    #[test]
+    fn keeps_largest_in_order() {
+        assert_eq!(top_k([5, 1, 9, 3, 7, 2], 3), vec![9, 7, 5]);
+        assert_eq!(top_k([5, 1], 3), vec![5, 1]);
+        assert_eq!(top_k([5, 1], 0), vec![]);
+    }
+
+    #[test]
+    fn earlier_items_win_ties() {
+        let items = [("a", 2), ("b", 3), ("c", 2), ("d", 3), ("e", 1)];
+        assert_eq!(top_k_by_key(items, 3, |(_, value)| *value), vec![("b", 3), ("d", 3), ("a", 2)]);
+    }
+
+    #[test]
+    fn matches_sorting() {
+        let items = (0..1000u64).map(|n| n * 7919 % 1009).collect::<Vec<_>>();
+        let mut sorted = items.clone();
+        sorted.sort_by(|a, b| b.cmp(a));
+        assert_eq!(top_k(items, 10), sorted[..10]);
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use crate::bench::{Format, measure, Settings, Timings};
+use crate::cli::Part;
+use crate::parse::{normalize, ParseError};
+
+/// A single day of the puzzle: parsing of the raw input into a typed model and both parts computed from it.
+pub(crate) trait Solution {
+    type Input;
+    type Output1: Display;
+    type Output2: Display;
+
+    fn parse(&self, content: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Self::Output1;
+    fn part2(&self, input: &Self::Input) -> Self::Output2;
+
+    /// Optional visualisation of how the answers were found, `colour` allows ANSI escape codes.
+    /// Days reporting tabular data lay it out in `format`.
+    fn report(&self, _input: &Self::Input, _colour: bool, _format: Format) -> Option<String> {
+        None
+    }
+
+    /// Overrides parameter `key` of the day, for instance to solve the smaller example of the puzzle.
+    fn configure(&mut self, key: &str, _value: &str) -> Result<(), String> {
+        Err(unknown_parameter(key))
+    }
+}
+
+pub(crate) fn unknown_parameter(key: &str) -> String {
+    format!("unknown parameter '{}'", key)
+}
+
+/// Parses the new value of parameter `key` in `Solution::configure`.
+pub(crate) fn parameter<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value.trim().parse().map_err(|_| format!("invalid value '{}' for parameter '{}'", value, key))
+}
+
+/// Answer of a part that the input may not have, shown as `-` when it is missing.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Maybe<T>(pub(crate) Option<T>);
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(answer) => answer.fmt(f),
+            None => write!(f, "-")
+        }
+    }
+}
+
+pub(crate) struct Answers {
+    pub(crate) part1: Option<String>,
+    pub(crate) part2: Option<String>,
+}
+
+/// Object-safe view of a `Solution`, so days with different input and output types can be driven from one list.
+pub(crate) trait Puzzle {
+    fn run(&self, content: &str, part: Part) -> Result<Answers, ParseError>;
+    fn time(&self, content: &str, part: Part, settings: &Settings) -> Result<Timings, ParseError>;
+    fn report(&self, content: &str, colour: bool, format: Format) -> Result<Option<String>, ParseError>;
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String>;
+}
+
+impl<S: Solution> Puzzle for S {
+    fn run(&self, content: &str, part: Part) -> Result<Answers, ParseError> {
+        let input = self.parse(&normalize(content))?;
+        Ok(Answers {
+            part1: part.first().then(|| self.part1(&input).to_string()),
+            part2: part.second().then(|| self.part2(&input).to_string()),
+        })
+    }
+
+    fn time(&self, content: &str, part: Part, settings: &Settings) -> Result<Timings, ParseError> {
+        let content = normalize(content);
+        let input = self.parse(&content)?;
+        Ok(Timings {
+            parse: measure(settings, || self.parse(&content)),
+            part1: part.first().then(|| measure(settings, || self.part1(&input))),
+            part2: part.second().then(|| measure(settings, || self.part2(&input))),
+        })
+    }
+
+    fn report(&self, content: &str, colour: bool, format: Format) -> Result<Option<String>, ParseError> {
+        let input = self.parse(&normalize(content))?;
+        Ok(Solution::report(self, &input, colour, format))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        Solution::configure(self, key, value)
+    }
+}
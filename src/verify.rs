@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::io::ErrorKind;
+use itertools::{EitherOrBoth, Itertools};
+
+/// Recorded answers keyed by year, day, input label and part. The label is the file name of the input,
+/// or `<user>/<day>.txt` for cached inputs, so moving the inputs around does not invalidate the answers.
+///
+/// Stored as a plain text file where every answer is preceded by a `[year day input part]` header line,
+/// so that multi-line answers (like the day 10 screen) can be kept verbatim:
+///
+/// ```text
+/// [2022 7 7.txt 1]
+/// 1084134
+/// [2022 10 10.txt 2]
+/// ###  #  #
+/// ...
+/// ```
+pub(crate) struct Manifest {
+    path: String,
+    answers: BTreeMap<Key, String>,
+}
+
+/// Year, day, input label and part of an answer.
+type Key = (u16, u8, String, u8);
+
+/// Only a line of the full `[year day input part]` shape is a header, with a four digit year, a day of the
+/// event and part 1 or 2. Anything else, even when it starts with `[`, belongs to the answer above it.
+fn parse_header(line: &str) -> Option<Key> {
+    let header = line.strip_prefix("[")?.strip_suffix("]")?;
+    let (year, rest) = header.split_once(" ")?;
+    let (day, rest) = rest.split_once(" ")?;
+    let (input, part) = rest.rsplit_once(" ")?;
+    let digits = |value: &str, lengths: &[usize]| lengths.contains(&value.len()) && value.chars().all(|c| c.is_ascii_digit());
+    if !digits(year, &[4]) || !digits(day, &[1, 2]) || !matches!(part, "1" | "2") || input.is_empty() {
+        return None;
+    }
+    let day = day.parse().ok().filter(|day| (1..=25).contains(day))?;
+    Some((year.parse().ok()?, day, input.to_string(), part.parse().ok()?))
+}
+
+impl Manifest {
+    pub(crate) fn load(path: &str) -> Result<Manifest, String> {
+        let content = match read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("cannot read '{}': {}", path, e))
+        };
+        let mut answers = BTreeMap::new();
+        let mut current: Option<(Key, Vec<&str>)> = None;
+        for (index, line) in content.lines().enumerate() {
+            if let Some(key) = parse_header(line) {
+                if let Some((key, lines)) = current.replace((key, vec![])) {
+                    answers.insert(key, lines.join("\n").trim_end_matches("\n").to_string());
+                }
+            } else {
+                match current.as_mut() {
+                    Some((_, lines)) => lines.push(line),
+                    None if line.trim().is_empty() => {}
+                    None => return Err(format!("{}:{}: answer without a [year day input part] header: '{}'", path, index + 1, line))
+                }
+            }
+        }
+        if let Some((key, lines)) = current {
+            answers.insert(key, lines.join("\n").trim_end_matches("\n").to_string());
+        }
+        Ok(Manifest { path: path.to_string(), answers })
+    }
+
+    pub(crate) fn get(&self, year: u16, day: u8, input: &str, part: u8) -> Option<&String> {
+        self.answers.get(&(year, day, input.to_string(), part))
+    }
+
+    pub(crate) fn insert(&mut self, year: u16, day: u8, input: &str, part: u8, answer: &str) {
+        self.answers.insert((year, day, input.to_string(), part), answer.to_string());
+    }
+
+    pub(crate) fn save(&self) -> Result<(), String> {
+        let content = self.answers.iter()
+            .map(|((year, day, input, part), answer)| format!("[{} {} {} {}]\n{}\n", year, day, input, part, answer))
+            .join("");
+        write(&self.path, content).map_err(|e| format!("cannot write '{}': {}", self.path, e))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Status {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+/// Line by line comparison, trailing whitespace is ignored since editors tend to strip it from the manifest.
+pub(crate) fn check(expected: Option<&String>, actual: &str) -> Status {
+    match expected {
+        None => Status::Missing,
+        Some(expected) => {
+            let expected_lines = expected.lines().map(str::trim_end).collect_vec();
+            let actual_lines = actual.lines().map(str::trim_end).collect_vec();
+            if expected_lines == actual_lines {
+                Status::Pass
+            } else {
+                Status::Fail(diff(&expected_lines, &actual_lines))
+            }
+        }
+    }
+}
+
+fn diff(expected: &[&str], actual: &[&str]) -> String {
+    if expected.len() <= 1 && actual.len() <= 1 {
+        return format!("expected {}, got {}", expected.join(""), actual.join(""));
+    }
+    expected.iter()
+        .zip_longest(actual.iter())
+        .map(|lines| match lines {
+            EitherOrBoth::Both(e, a) if e == a => format!("  {}", e),
+            EitherOrBoth::Both(e, a) => format!("- {}\n+ {}", e, a),
+            EitherOrBoth::Left(e) => format!("- {}", e),
+            EitherOrBoth::Right(a) => format!("+ {}", a),
+        })
+        .join("\n")
+}
+
+#[derive(Default)]
+pub(crate) struct Summary {
+    pub(crate) passed: usize,
+    pub(crate) failed: usize,
+    pub(crate) missing: usize,
+}
+
+impl Summary {
+    pub(crate) fn report(&mut self, day: u8, input: &str, part: u8, status: &Status) {
+        let label = format!("Day {:>2} part {} ({})", day, part, input);
+        match status {
+            Status::Pass => {
+                self.passed += 1;
+                println!("{}: PASS", label);
+            }
+            Status::Fail(diff) => {
+                self.failed += 1;
+                println!("{}: FAIL", label);
+                for line in diff.lines() {
+                    println!("    {}", line);
+                }
+            }
+            Status::Missing => {
+                self.missing += 1;
+                println!("{}: MISSING", label);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::remove_file;
+    use super::*;
+
+    fn path(name: &str) -> String {
+        temp_dir().join(format!("aoc-answers-{}-{}.txt", name, std::process::id())).to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn headers() {
+        assert_eq!(parse_header("[2022 7 7.txt 1]"), Some((2022, 7, "7.txt".to_string(), 1)));
+        assert_eq!(parse_header("[2022 10 alice/my input.txt 2]"), Some((2022, 10, "alice/my input.txt".to_string(), 2)));
+        assert_eq!(parse_header("[22 7 7.txt 1]"), None);
+        assert_eq!(parse_header("[2022 26 26.txt 1]"), None);
+        assert_eq!(parse_header("[2022 7 7.txt 3]"), None);
+        assert_eq!(parse_header("[2022 7 1]"), None);
+        assert_eq!(parse_header("[C] [M]"), None);
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let path = path("round-trip");
+        let mut manifest = Manifest::load(&path).unwrap();
+        manifest.insert(2022, 5, "5.txt", 1, "[CMZ]");
+        manifest.insert(2022, 10, "alice/10.txt", 2, "##..\n[..]\n.##.");
+        manifest.insert(2022, 1, "1.txt", 1, "24000");
+        manifest.save().unwrap();
+        let loaded = Manifest::load(&path).unwrap();
+        remove_file(&path).unwrap();
+        assert_eq!(loaded.answers, manifest.answers);
+        assert_eq!(loaded.get(2022, 10, "alice/10.txt", 2).map(String::as_str), Some("##..\n[..]\n.##."));
+        assert_eq!(loaded.get(2022, 10, "10.txt", 2), None);
+    }
+
+    #[test]
+    fn answer_without_header_is_rejected() {
+        let path = path("headless");
+        write(&path, "\n[2022 1]\n24000\n").unwrap();
+        let error = Manifest::load(&path).err().unwrap();
+        remove_file(&path).unwrap();
+        assert!(error.ends_with(":2: answer without a [year day input part] header: '[2022 1]'"));
+    }
+
+    #[test]
+    fn checks() {
+        let expected = "24000".to_string();
+        assert_eq!(check(Some(&expected), "24000"), Status::Pass);
+        assert_eq!(check(Some(&expected), "23000"), Status::Fail("expected 24000, got 23000".to_string()));
+        assert_eq!(check(None, "24000"), Status::Missing);
+        let screen = "#..# \n.##.".to_string();
+        assert_eq!(check(Some(&screen), "#..#\n.##.\n"), Status::Pass);
+        assert_eq!(check(Some(&screen), "#..#\n.#..\n...."), Status::Fail("  #..#\n- .##.\n+ .#..\n+ ....".to_string()));
+    }
+}
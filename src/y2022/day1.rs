@@ -0,0 +1,355 @@
+use std::io::BufRead;
+use itertools::Itertools;
+use crate::bench::Format;
+use crate::parse::{Expected, lines, number, ParseError};
+use crate::select::top_k_by_key;
+use crate::solution::{parameter, Solution, unknown_parameter};
+
+/// Calories carried by one elf, `elf` counting from 0 in input order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct ElfTotal {
+    pub(crate) elf: usize,
+    pub(crate) items: usize,
+    pub(crate) total: i64,
+}
+
+/// Totals of the elves read line by line, so only one elf is held in memory at a time.
+pub(crate) struct ElfTotals<R> {
+    reader: R,
+    bytes: Vec<u8>,
+    line: usize,
+    elf: usize,
+}
+
+impl<R: BufRead> ElfTotals<R> {
+    pub(crate) fn new(reader: R) -> ElfTotals<R> {
+        ElfTotals { reader, bytes: vec![], line: 0, elf: 0 }
+    }
+
+    /// The next line without its line ending, or the error of reading or decoding it located on that line.
+    fn next_line(&mut self) -> Option<Result<String, ParseError>> {
+        self.bytes.clear();
+        let read = self.reader.read_until(b'\n', &mut self.bytes);
+        if let Ok(0) = read {
+            return None;
+        }
+        self.line += 1;
+        let text = String::from_utf8_lossy(&self.bytes);
+        let line = text.strip_suffix('\n').map(|line| line.strip_suffix('\r').unwrap_or(line)).unwrap_or(&text);
+        let error = match (read, std::str::from_utf8(&self.bytes)) {
+            (Err(e), _) => ParseError::new(line, line, Expected::Format(format!("a readable line ({})", e))),
+            (_, Err(e)) => ParseError::new(line, &line[e.valid_up_to()..], Expected::Format("UTF-8 text".to_string())),
+            _ => return Some(Ok(line.to_string())),
+        };
+        Some(Err(error.shifted(self.line - 1)))
+    }
+}
+
+impl<R: BufRead> Iterator for ElfTotals<R> {
+    type Item = Result<ElfTotal, ParseError>;
+
+    fn next(&mut self) -> Option<Result<ElfTotal, ParseError>> {
+        let mut current = ElfTotal { elf: self.elf, items: 0, total: 0 };
+        while let Some(line) = self.next_line() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            if line.is_empty() {
+                if current.items > 0 {
+                    break;
+                }
+                continue;
+            }
+            match number::<i64>(&line, &line) {
+                Ok(calories) => {
+                    current.items += 1;
+                    current.total += calories;
+                }
+                Err(e) => return Some(Err(e.shifted(self.line - 1)))
+            }
+        }
+        return if current.items > 0 {
+            self.elf += 1;
+            Some(Ok(current))
+        } else {
+            None
+        };
+    }
+}
+
+/// The `k` elves carrying the most calories, most first.
+fn ranking(elfs: &Vec<ElfTotal>, k: usize) -> Vec<ElfTotal> {
+    top_k_by_key(elfs.iter().copied(), k, |elf| elf.total)
+}
+
+fn part1(elfs: &Vec<ElfTotal>) -> i64 {
+    elfs.iter()
+        .map(|elf| elf.total)
+        .max()
+        .unwrap()
+}
+
+fn part2(elfs: &Vec<ElfTotal>) -> i64 {
+    ranking(elfs, 3)
+        .iter()
+        .map(|elf| elf.total)
+        .sum()
+}
+
+/// Percentage of `overall` that `total` makes up, 0 when nobody carries anything.
+fn share(total: i64, overall: i64) -> String {
+    let share = if overall == 0 { 0.0 } else { total as f64 * 100.0 / overall as f64 };
+    format!("{:.2}", share)
+}
+
+fn table(ranking: &Vec<ElfTotal>, elves: usize, overall: i64) -> String {
+    let mut lines = vec![format!("{:>4}  {:>5}  {:>5}  {:>10}  {:>7}", "Rank", "Elf", "Items", "Calories", "Share")];
+    for (rank, elf) in ranking.iter().enumerate() {
+        lines.push(format!("{:>4}  {:>5}  {:>5}  {:>10}  {:>6}%", rank + 1, elf.elf, elf.items, elf.total, share(elf.total, overall)));
+    }
+    let (items, total) = (ranking.iter().map(|elf| elf.items).sum::<usize>(), ranking.iter().map(|elf| elf.total).sum());
+    lines.push(format!("{:>4}  {:>5}  {:>5}  {:>10}  {:>6}%", "Top", format!("{}/{}", ranking.len(), elves), items, total, share(total, overall)));
+    lines.join("\n")
+}
+
+fn json(ranking: &Vec<ElfTotal>, overall: i64) -> String {
+    let entries = ranking.iter()
+        .enumerate()
+        .map(|(rank, elf)| format!(
+            "  {{\"rank\": {}, \"elf\": {}, \"items\": {}, \"calories\": {}, \"share\": {}}}",
+            rank + 1, elf.elf, elf.items, elf.total, share(elf.total, overall)))
+        .join(",\n");
+    format!("[\n{}\n]", entries)
+}
+
+fn csv(ranking: &Vec<ElfTotal>, overall: i64) -> String {
+    let mut lines = vec!["rank,elf,items,calories,share".to_string()];
+    for (rank, elf) in ranking.iter().enumerate() {
+        lines.push(format!("{},{},{},{},{}", rank + 1, elf.elf, elf.items, elf.total, share(elf.total, overall)));
+    }
+    lines.join("\n")
+}
+
+/// The elves carrying the most calories with their share of what all elves carry together.
+fn report(elfs: &Vec<ElfTotal>, top: usize, format: Format) -> String {
+    let ranking = ranking(elfs, top);
+    let overall = elfs.iter().map(|elf| elf.total).sum();
+    match format {
+        Format::Table => table(&ranking, elfs.len(), overall),
+        Format::Json => json(&ranking, overall),
+        Format::Csv => csv(&ranking, overall),
+    }
+}
+
+pub(crate) struct Day1 {
+    /// Elves listed by the report.
+    pub(crate) top: usize,
+}
+
+impl Default for Day1 {
+    fn default() -> Day1 {
+        Day1 { top: 3 }
+    }
+}
+
+impl Solution for Day1 {
+    type Input = Vec<ElfTotal>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, contents: &str) -> Result<Vec<ElfTotal>, ParseError> {
+        let elfs = ElfTotals::new(contents.as_bytes()).collect::<Result<Vec<_>, _>>()?;
+        if elfs.is_empty() {
+            let expected = Expected::Format("the calories of at least one elf".to_string());
+            return Err(ParseError::new("", "", expected).shifted(lines(contents).count() - 1));
+        }
+        Ok(elfs)
+    }
+
+    fn part1(&self, elfs: &Vec<ElfTotal>) -> i64 {
+        part1(elfs)
+    }
+
+    fn part2(&self, elfs: &Vec<ElfTotal>) -> i64 {
+        part2(elfs)
+    }
+
+    fn report(&self, elfs: &Vec<ElfTotal>, _colour: bool, format: Format) -> Option<String> {
+        Some(report(elfs, self.top, format))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "top" => self.top = parameter(key, value)?,
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::process_results;
+    use crate::cli::Part;
+    use crate::solution::Puzzle;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn part1_example() {
+        let elfs = Day1::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::default().part1(&elfs), 24000);
+    }
+
+    #[test]
+    fn part2_example() {
+        let elfs = Day1::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day1::default().part2(&elfs), 45000);
+    }
+
+    #[test]
+    fn single_elf() {
+        let elfs = Day1::default().parse("5\n7").unwrap();
+        assert_eq!(Day1::default().part1(&elfs), 12);
+        assert_eq!(Day1::default().part2(&elfs), 12);
+    }
+
+    #[test]
+    fn ranking_table() {
+        let elfs = Day1::default().parse(EXAMPLE).unwrap();
+        assert_eq!(report(&elfs, 2, Format::Table), "\
+Rank    Elf  Items    Calories    Share
+   1      3      3       24000   43.64%
+   2      2      2       11000   20.00%
+ Top    2/5      5       35000   63.64%");
+    }
+
+    #[test]
+    fn ranking_json_and_csv() {
+        let elfs = Day1::default().parse(EXAMPLE).unwrap();
+        assert_eq!(report(&elfs, 1, Format::Json), "[\n  {\"rank\": 1, \"elf\": 3, \"items\": 3, \"calories\": 24000, \"share\": 43.64}\n]");
+        assert_eq!(report(&elfs, 10, Format::Csv).lines().count(), 6);
+    }
+
+    #[test]
+    fn configure_report() {
+        let mut day = Day1::default();
+        Solution::configure(&mut day, "top", "5").unwrap();
+        assert_eq!(day.top, 5);
+        assert!(Solution::configure(&mut day, "format", "json").is_err());
+    }
+
+    #[test]
+    fn report_takes_the_requested_format() {
+        let report = Puzzle::report(&Day1::default(), EXAMPLE, false, Format::Csv).unwrap().unwrap();
+        assert_eq!(report.lines().next(), Some("rank,elf,items,calories,share"));
+    }
+
+    #[test]
+    fn shares_of_empty_packs() {
+        let elfs = Day1::default().parse("0\n\n0").unwrap();
+        assert_eq!(report(&elfs, 1, Format::Json), "[\n  {\"rank\": 1, \"elf\": 0, \"items\": 1, \"calories\": 0, \"share\": 0.00}\n]");
+    }
+
+    #[test]
+    fn streamed_totals() {
+        let totals = ElfTotals::new(EXAMPLE.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(totals.len(), 5);
+        assert_eq!(totals[1], ElfTotal { elf: 1, items: 1, total: 4000 });
+        let top = ranking(&totals, 3);
+        assert_eq!(top.iter().map(|elf| elf.elf).collect::<Vec<_>>(), vec![3, 2, 4]);
+        assert_eq!(top.iter().map(|elf| elf.total).sum::<i64>(), 45000);
+    }
+
+    #[test]
+    fn streamed_totals_report_bad_line() {
+        let error = Day1::default().parse("1\n\n2\nx3").err().unwrap();
+        assert_eq!(error.to_string(), "line 4, column 1: expected a number, found \"x3\"");
+    }
+
+    #[test]
+    fn input_without_elves_is_reported() {
+        let error = Day1::default().parse("\n\n").err().unwrap();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.expected, Expected::Format("the calories of at least one elf".to_string()));
+        assert!(Day1::default().parse("").is_err());
+    }
+
+    /// Reader failing after `content` was read.
+    struct Failing<'a> {
+        content: &'a [u8],
+    }
+
+    impl std::io::Read for Failing<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            if self.content.is_empty() {
+                return Err(std::io::Error::other("disk on fire"));
+            }
+            self.content.read(buffer)
+        }
+    }
+
+    #[test]
+    fn streamed_totals_report_unreadable_line() {
+        let reader = std::io::BufReader::new(Failing { content: b"1\n\n23" });
+        let error = ElfTotals::new(reader).nth(1).unwrap().err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (3, 1, "23"));
+        assert_eq!(error.expected, Expected::Format("a readable line (disk on fire)".to_string()));
+        let error = ElfTotals::new(&b"1\n2\xff3\n"[..]).next().unwrap().err().unwrap();
+        assert_eq!(error.to_string(), "line 2, column 2: expected UTF-8 text, found \"\u{fffd}3\"");
+    }
+
+    /// Input where elf n carries n items of n calories, generated while it is read.
+    struct Generated {
+        elf: usize,
+        elves: usize,
+        pending: Vec<u8>,
+    }
+
+    impl std::io::Read for Generated {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            if self.pending.is_empty() && self.elf < self.elves {
+                self.elf += 1;
+                self.pending = (format!("{}\n", self.elf).repeat(self.elf) + "\n").into_bytes();
+            }
+            let size = buffer.len().min(self.pending.len());
+            buffer[..size].copy_from_slice(&self.pending[..size]);
+            self.pending.drain(..size);
+            Ok(size)
+        }
+    }
+
+    #[test]
+    fn streamed_totals_of_large_input() {
+        let reader = std::io::BufReader::new(Generated { elf: 0, elves: 2000, pending: vec![] });
+        let top = process_results(ElfTotals::new(reader), |totals| top_k_by_key(totals, 2, |elf| elf.total)).unwrap();
+        assert_eq!(top, vec![
+            ElfTotal { elf: 1999, items: 2000, total: 2000 * 2000 },
+            ElfTotal { elf: 1998, items: 1999, total: 1999 * 1999 },
+        ]);
+    }
+
+    #[test]
+    fn crlf_and_trailing_newline() {
+        let content = EXAMPLE.replace("\n", "\r\n") + "\r\n";
+        let answers = Day1::default().run(&content, Part::Both).unwrap();
+        assert_eq!(answers.part1.as_deref(), Some("24000"));
+        assert_eq!(answers.part2.as_deref(), Some("45000"));
+    }
+}
@@ -0,0 +1,265 @@
+use itertools::Itertools;
+use crate::parse::{Expected, number, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub(crate) enum Operation {
+    Noop,
+    Add(i32),
+}
+
+impl Operation {
+    fn new(line: &str) -> Result<Operation, ParseError> {
+        match line.split_once(" ") {
+            None if line == "noop" => Ok(Operation::Noop),
+            Some(("addx", value)) => Ok(Operation::Add(number(line, value)?)),
+            _ => Err(ParseError::new(line, line, Expected::OneOf("noop or addx <value>".to_string())))
+        }
+    }
+}
+
+fn simulate(operations: &Vec<Operation>) -> Vec<i32> {
+    let mut register_history = vec![1];
+    for op in operations {
+        let last = register_history.last().unwrap().clone();
+        match op {
+            Operation::Noop => register_history.push(last),
+            Operation::Add(v) => {
+                register_history.push(last);
+                register_history.push(last + v);
+            }
+        }
+    }
+    return register_history;
+}
+
+fn part2(operations: &Vec<Operation>) -> String {
+    let register_history = simulate(operations);
+    let mut screen = vec![];
+    // The last value is the register after the final cycle, no pixel is drawn with it.
+    for cycle in 0..register_history.len() - 1 {
+        let sprite_middle = register_history.get(cycle).unwrap();
+        if (cycle as i32 % 40 - sprite_middle).abs() <= 1 {
+            screen.push("#");
+        } else {
+            screen.push(" ");
+        }
+    }
+    screen.chunks(40)
+        .map(|row| row.join(""))
+        .join("\n")
+}
+
+fn part1(operations: &Vec<Operation>) -> i32 {
+    let register_history = simulate(operations);
+    (20..register_history.len()).step_by(40)
+        .map(|cycle| (cycle as i32) * register_history.get((cycle - 1) as usize).unwrap())
+        .sum()
+}
+
+pub(crate) struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Operation>;
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(&self, content: &str) -> Result<Vec<Operation>, ParseError> {
+        parse_lines(content, Operation::new)
+    }
+
+    fn part1(&self, operations: &Vec<Operation>) -> i32 {
+        part1(operations)
+    }
+
+    fn part2(&self, operations: &Vec<Operation>) -> String {
+        part2(operations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    #[test]
+    fn part1_example() {
+        let operations = Day10.parse(EXAMPLE).unwrap();
+        assert_eq!(Day10.part1(&operations), 13140);
+    }
+
+    #[test]
+    fn part2_example() {
+        let operations = Day10.parse(EXAMPLE).unwrap();
+        let expected = [
+            "##  ##  ##  ##  ##  ##  ##  ##  ##  ##  ",
+            "###   ###   ###   ###   ###   ###   ### ",
+            "####    ####    ####    ####    ####    ",
+            "#####     #####     #####     #####     ",
+            "######      ######      ######      ####",
+            "#######       #######       #######     ",
+        ];
+        assert_eq!(Day10.part2(&operations), expected.join("\n"));
+    }
+
+    #[test]
+    fn small_program() {
+        let operations = Day10.parse("noop\naddx 3\naddx -5").unwrap();
+        assert_eq!(simulate(&operations), vec![1, 1, 1, 4, 4, -1]);
+    }
+
+    #[test]
+    fn screen_has_one_pixel_per_cycle() {
+        // 5 cycles, the sprite covers every pixel drawn and the final -1 is never drawn.
+        let operations = Day10.parse("noop\naddx 3\naddx -5").unwrap();
+        assert_eq!(Day10.part2(&operations), "#####");
+        let rows = Day10.part2(&Day10.parse(EXAMPLE).unwrap());
+        assert!(rows.lines().all(|row| row.len() == 40));
+    }
+}
@@ -0,0 +1,241 @@
+use std::ops::Div;
+use itertools::{Itertools, sorted};
+use regex::Regex;
+use crate::parse::{Expected, lines, number, parse_blocks, ParseError};
+use crate::solution::Solution;
+
+#[derive(Clone)]
+enum Op {
+    Mul,
+    Add,
+    Square,
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<i64>,
+    divisor: i64,
+    test_true: usize,
+    test_false: usize,
+    op: Op,
+    operand: i64,
+}
+
+fn compute(item: &i64, op: &Op, operand: i64) -> i64 {
+    return match op {
+        Op::Mul => item * operand,
+        Op::Add => item + operand,
+        Op::Square => item * item
+    };
+}
+
+impl Monkey {
+    fn handle_items(&self, reducer: impl Fn(i64) -> i64) -> Vec<(usize, i64)> {
+        let mut res = vec![];
+        for item in &self.items {
+            let new_item = compute(&item, &self.op, self.operand);
+            let reduced = reducer(new_item);
+            if (reduced % self.divisor) == 0 {
+                res.push((self.test_true, reduced));
+            } else {
+                res.push((self.test_false, reduced));
+            }
+        }
+        res
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Monkeys {
+    monkeys: Vec<Monkey>,
+    activity: Vec<i64>,
+}
+
+impl Monkeys {
+    fn play_round(&mut self, reducer: &impl Fn(i64) -> i64) {
+        for i in 0..self.monkeys.len() {
+            let monkey = self.monkeys.get(i).unwrap();
+            let moved_items = monkey.handle_items(reducer);
+            self.activity[i] = self.activity[i] + monkey.items.len() as i64;
+            self.monkeys.get_mut(i).unwrap().items = vec![];
+            for (target_monkey, value) in moved_items {
+                self.monkeys.get_mut(target_monkey).unwrap().items.push(value);
+            }
+        }
+    }
+
+    fn activity_score(&self) -> i64 {
+        let sorted = sorted(self.activity.iter()).rev().collect_vec();
+        sorted[0] * sorted[1]
+    }
+}
+
+fn parse_numbers(line: &str) -> Result<Vec<i64>, ParseError> {
+    let pattern = Regex::new(r"\d+").unwrap();
+    pattern.find_iter(line)
+        .map(|m| number(line, m.as_str()))
+        .collect()
+}
+
+/// The first number of `line` with the text it was read from, so that errors can point at it.
+fn get_number(line: &str) -> Result<(i64, &str), ParseError> {
+    let token = Regex::new(r"\d+").unwrap()
+        .find(line)
+        .map(|m| m.as_str())
+        .ok_or_else(|| ParseError::new(line, line, Expected::Number))?;
+    Ok((number(line, token)?, token))
+}
+
+/// The monkey an item is thrown to, one of the `count` monkeys.
+fn get_target(line: &str, count: usize) -> Result<usize, ParseError> {
+    let (target, token) = get_number(line)?;
+    if (target as usize) < count {
+        Ok(target as usize)
+    } else {
+        Err(ParseError::new(line, token, Expected::Format(format!("a monkey between 0 and {}", count - 1))))
+    }
+}
+
+fn parse_monkey(data: &str, count: usize) -> Result<Monkey, ParseError> {
+    let first_line = lines(data).next().unwrap_or_default();
+    let x: (&str, &str, &str, &str, &str) = lines(data).skip(1).collect_tuple()
+        .ok_or_else(|| ParseError::new(first_line, first_line, Expected::Format("a monkey description of 6 lines".to_string())))?;
+    let items = parse_numbers(x.0).map_err(|e| e.shifted(1))?;
+    let operands = parse_numbers(x.1).map_err(|e| e.shifted(2))?;
+    let op;
+    if x.1.contains("+") {
+        op = Op::Add;
+    } else if (x.1.contains("*")) & (operands.len() > 0) {
+        op = Op::Mul;
+    } else {
+        op = Op::Square;
+    }
+    let operand = operands.get(0).unwrap_or(&0).clone();
+    let divisor = match get_number(x.2).map_err(|e| e.shifted(3))? {
+        (0, token) => return Err(ParseError::new(x.2, token, Expected::Format("a divisor other than 0".to_string())).shifted(3)),
+        (divisor, _) => divisor
+    };
+    let test_true = get_target(x.3, count).map_err(|e| e.shifted(4))?;
+    let test_false = get_target(x.4, count).map_err(|e| e.shifted(5))?;
+    Ok(Monkey {
+        items,
+        divisor,
+        test_true,
+        test_false,
+        op,
+        operand,
+    })
+}
+
+/// There have to be two monkeys to score the monkey business, and items can only be thrown to monkeys that exist.
+fn parse_input(content: &str) -> Result<Monkeys, ParseError> {
+    let count = content.split("\n\n").count();
+    let monkeys = parse_blocks(content, |block| parse_monkey(block, count))?;
+    if count < 2 {
+        return Err(ParseError::new("", "", Expected::Format("at least two monkeys".to_string())).shifted(lines(content).count() - 1));
+    }
+    Ok(Monkeys { monkeys, activity: vec![0; count] })
+}
+
+
+fn play(mut monkeys: Monkeys, rounds: i64, reducer: &impl Fn(i64) -> i64) -> i64 {
+    for _ in 0..rounds {
+        monkeys.play_round(reducer)
+    }
+    monkeys.activity_score()
+}
+
+fn part1(monkeys: &Monkeys) -> i64 {
+    play(monkeys.clone(), 20, &|x| x.div(3))
+}
+
+fn part2(monkeys: &Monkeys) -> i64 {
+    let d: i64 = monkeys.monkeys.iter().map(|x| x.divisor).product();
+    play(monkeys.clone(), 10000, &|x| x % d)
+}
+
+pub(crate) struct Day11;
+
+impl Solution for Day11 {
+    type Input = Monkeys;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, content: &str) -> Result<Monkeys, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(&self, monkeys: &Monkeys) -> i64 {
+        part1(monkeys)
+    }
+
+    fn part2(&self, monkeys: &Monkeys) -> i64 {
+        part2(monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1";
+
+    #[test]
+    fn part1_example() {
+        let monkeys = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part1(&monkeys), 10605);
+    }
+
+    #[test]
+    fn part2_example() {
+        let monkeys = Day11.parse(EXAMPLE).unwrap();
+        assert_eq!(Day11.part2(&monkeys), 2713310158);
+    }
+
+    #[test]
+    fn invalid_monkeys_are_reported() {
+        let error = Day11.parse(&EXAMPLE.replace("throw to monkey 0", "throw to monkey 4")).err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (13, 31, "4"));
+        assert_eq!(error.expected, Expected::Format("a monkey between 0 and 3".to_string()));
+        let error = Day11.parse(&EXAMPLE.replace("divisible by 13", "divisible by 0")).err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (18, 22, "0"));
+        let single = EXAMPLE.split("\n\n").next().unwrap().replace("monkey 2", "monkey 0").replace("monkey 3", "monkey 0");
+        let error = Day11.parse(&single).err().unwrap();
+        assert_eq!((error.line, error.expected), (6, Expected::Format("at least two monkeys".to_string())));
+        assert_eq!(Day11.parse("").err().unwrap().line, 1);
+    }
+
+    #[test]
+    fn activity_after_one_round() {
+        let mut monkeys = Day11.parse(EXAMPLE).unwrap();
+        monkeys.play_round(&|x| x / 3);
+        assert_eq!(monkeys.activity, vec![2, 4, 3, 5]);
+    }
+}
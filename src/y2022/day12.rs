@@ -0,0 +1,245 @@
+use std::collections::{HashMap, HashSet};
+use itertools::Itertools;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::{Expected, ParseError};
+use crate::search::{self, bfs, Search};
+use crate::bench::Format;
+use crate::solution::Solution;
+
+pub(crate) struct Graph {
+    grid: Grid<char>,
+    edges: HashMap<Point, Vec<Point>>,
+    reversed_edges: HashMap<Point, Vec<Point>>,
+    start: Point,
+    end: Point,
+    low: HashSet<Point>,
+}
+
+/// The hill walked downwards from the end, following every edge of the graph backwards.
+struct Reversed<'a>(&'a Graph);
+
+impl search::Graph for Reversed<'_> {
+    type Node = Point;
+
+    fn neighbours(&self, node: &Point) -> Vec<(Point, u64)> {
+        self.0.reversed_edges[node].iter().map(|neighbour| (*neighbour, 1)).collect_vec()
+    }
+}
+
+impl Graph {
+    fn height(symbol: &char) -> i32 {
+        let x = match symbol {
+            'S' => 'a',
+            'E' => 'z',
+            a => *a
+        };
+        x as i32 - 'a' as i32
+    }
+
+    fn height_diff(n1: Point, n2: Point, grid: &Grid<char>) -> i32 {
+        let current = Graph::height(&grid[n1]);
+        let next = Graph::height(&grid[n2]);
+        next - current
+    }
+
+    fn legal_moves(node: Point, grid: &Grid<char>) -> Vec<Point> {
+        grid.neighbours4(node)
+            .filter(|n2| Graph::height_diff(node, *n2, grid) <= 1)
+            .collect_vec()
+    }
+
+    fn new(content: &str) -> Result<Graph, ParseError> {
+        let expected = Expected::Format("a height a-z, S or E".to_string());
+        let grid = Grid::parse(content, |c| matches!(c, 'a'..='z' | 'S' | 'E'), expected)?;
+        let mut low = HashSet::new();
+        let mut edges = HashMap::new();
+        let mut reversed_edges: HashMap<Point, Vec<Point>> = grid.positions().map(|node| (node, vec![])).collect();
+        for (node, symbol) in grid.iter() {
+            if *symbol == 'S' || *symbol == 'a' {
+                low.insert(node);
+            }
+            let moves = Graph::legal_moves(node, &grid);
+            for next in &moves {
+                reversed_edges.get_mut(next).unwrap().push(node);
+            }
+            edges.insert(node, moves);
+        }
+        let missing = |symbol: &str| ParseError::new("", "", Expected::Format(format!("a position marked with {}", symbol)))
+            .shifted(grid.height());
+        let start = grid.find(|c| *c == 'S').ok_or_else(|| missing("S"))?;
+        let end = grid.find(|c| *c == 'E').ok_or_else(|| missing("E"))?;
+        Ok(Graph {
+            grid,
+            edges,
+            reversed_edges,
+            start,
+            end,
+            low,
+        })
+    }
+
+    fn shortest_path(&self, src: &Point) -> Option<Vec<Point>> {
+        bfs(self, [*src], |node| *node == self.end).goal_path()
+    }
+
+    /// Single search backwards from the end, reaching every cell the end can be reached from.
+    fn search_from_end(&self) -> Search<Point> {
+        bfs(&Reversed(self), [self.end], |_| false)
+    }
+
+    /// Number of steps from every cell that can reach the end, found by a single search backwards from it.
+    pub(crate) fn distances_to_end(&self) -> HashMap<Point, u64> {
+        self.search_from_end()
+            .reached()
+            .map(|(node, distance)| (*node, distance))
+            .collect()
+    }
+
+    /// Lowest cell closest to the end together with the path from it, `None` when no low cell reaches the end.
+    /// The path is read from the predecessors of the backwards search, which lead from the start to the end.
+    pub(crate) fn best_start(&self) -> Option<(Point, Vec<Point>)> {
+        let search = self.search_from_end();
+        let start = *self.low.iter()
+            .filter(|node| search.contains(node))
+            .min_by_key(|node| (search.cost(node), **node))?;
+        let mut path = search.path(&start)?;
+        path.reverse();
+        Some((start, path))
+    }
+
+    /// The height map with `path` drawn over it as arrows pointing to the next cell.
+    /// With `colour` the cells are shaded from dark to light by elevation and the path is highlighted.
+    pub(crate) fn render(&self, path: &[Point], colour: bool) -> String {
+        let arrows: HashMap<Point, char> = path.iter()
+            .tuple_windows()
+            .filter_map(|(from, to)| Direction::between(*from, *to).map(|direction| (*from, direction.arrow())))
+            .collect();
+        (0..self.grid.height() as i32)
+            .map(|y| (0..self.grid.width() as i32)
+                .map(|x| {
+                    let position = Point::new(x, y);
+                    let symbol = self.grid[position];
+                    match (arrows.get(&position), colour) {
+                        (Some(arrow), false) => arrow.to_string(),
+                        (None, false) => symbol.to_string(),
+                        (arrow, true) => {
+                            let shade = 232 + Graph::height(&symbol) * 23 / 25;
+                            let foreground = if arrow.is_some() { "1;33" } else { "37" };
+                            format!("\x1b[{};48;5;{}m{}\x1b[0m", foreground, shade, arrow.unwrap_or(&symbol))
+                        }
+                    }
+                })
+                .collect::<String>())
+            .join("\n")
+    }
+}
+
+impl search::Graph for Graph {
+    type Node = Point;
+
+    fn neighbours(&self, node: &Point) -> Vec<(Point, u64)> {
+        self.edges[node].iter().map(|neighbour| (*neighbour, 1)).collect_vec()
+    }
+}
+
+fn report(graph: &Graph, colour: bool) -> String {
+    let mut sections = vec![];
+    if let Some(path) = graph.shortest_path(&graph.start) {
+        sections.push(format!("Part 1: {} steps from S\n{}", path.len() - 1, graph.render(&path, colour)));
+    }
+    if let Some((start, path)) = graph.best_start() {
+        sections.push(format!("Part 2: {} steps from ({}, {})\n{}", path.len() - 1, start.x, start.y, graph.render(&path, colour)));
+    }
+    sections.join("\n\n")
+}
+
+fn part2(graph: &Graph) -> i32 {
+    let distances = graph.distances_to_end();
+    *graph.low.iter().filter_map(|node| distances.get(node)).min().unwrap() as i32
+}
+
+fn part1(graph: &Graph) -> i32 {
+    let path = graph.shortest_path(&graph.start).unwrap();
+    path.len() as i32 - 1
+}
+
+pub(crate) struct Day12;
+
+impl Solution for Day12 {
+    type Input = Graph;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Graph, ParseError> {
+        Graph::new(content)
+    }
+
+    fn part1(&self, graph: &Graph) -> i32 {
+        part1(graph)
+    }
+
+    fn part2(&self, graph: &Graph) -> i32 {
+        part2(graph)
+    }
+
+    fn report(&self, graph: &Graph, colour: bool, _format: Format) -> Option<String> {
+        Some(report(graph, colour))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+
+    #[test]
+    fn part1_example() {
+        let graph = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part1(&graph), 31);
+    }
+
+    #[test]
+    fn part2_example() {
+        let graph = Day12.parse(EXAMPLE).unwrap();
+        assert_eq!(Day12.part2(&graph), 29);
+    }
+
+    #[test]
+    fn best_start_and_distances() {
+        let graph = Day12.parse(EXAMPLE).unwrap();
+        let (start, path) = graph.best_start().unwrap();
+        assert_eq!(start, Point::new(0, 4));
+        assert_eq!((path[0], path[path.len() - 1]), (start, graph.end));
+        assert_eq!(path.len() - 1, 29);
+        assert!(path.iter().tuple_windows().all(|(from, to)| graph.edges[from].contains(to)));
+        let distances = graph.distances_to_end();
+        assert_eq!(distances[&graph.end], 0);
+        assert_eq!(distances[&graph.start], 31);
+        assert_eq!(graph.low.iter().filter_map(|node| distances.get(node)).min(), Some(&29));
+    }
+
+    #[test]
+    fn path_is_drawn_with_arrows() {
+        let graph = Day12.parse(EXAMPLE).unwrap();
+        let path = graph.shortest_path(&graph.start).unwrap();
+        assert_eq!(graph.render(&path, false), "\
+>>vv<<<<
+abvvv<<^
+acvv>E^^
+acv>>>^^
+ab>>>>>^");
+        assert!(graph.render(&path, true).contains("\x1b[1;33;48;5;232m>"));
+    }
+
+    #[test]
+    fn missing_end_is_reported() {
+        assert!(Day12.parse("Sab\nabc").is_err());
+    }
+}
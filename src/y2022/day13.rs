@@ -1,8 +1,9 @@
 use std::cmp::Ordering;
-use std::fs::read_to_string;
 use itertools::{Itertools, sorted};
+use crate::parse::{Expected, lines, number, parse_blocks, ParseError};
+use crate::solution::Solution;
 
-struct Entry {
+pub(crate) struct Entry {
     list: Option<Vec<Entry>>,
     single: Option<i32>,
 }
@@ -86,54 +87,53 @@ impl Ord for Entry {
     }
 }
 
-fn extract_groups(data: &str) -> Vec<String> {
+fn extract_groups(data: &str) -> Vec<&str> {
     let mut res = vec![];
-    let mut current = String::new();
+    let mut start = 0;
     let mut nesting = 0;
-    for c in data.chars() {
+    for (index, c) in data.char_indices() {
         if c == '[' {
             nesting += 1;
         } else if c == ']' {
             nesting -= 1;
         } else if c == ',' && nesting == 0 {
-            res.push(current.to_string());
-            current = String::new();
-        }
-        if current.len() > 0 || c != ',' {
-            current += c.to_string().as_str();
+            res.push(&data[start..index]);
+            start = index + 1;
         }
     }
-    if current.len() > 0 {
-        res.push(current.to_string());
+    if start < data.len() {
+        res.push(&data[start..]);
     }
     res
 }
 
-fn parse_entry(value: &str) -> Entry {
+fn parse_entry(line: &str, value: &str) -> Result<Entry, ParseError> {
     return if value.starts_with("[") {
+        if value.len() < 2 || !value.ends_with("]") {
+            return Err(ParseError::new(line, &value[value.len()..], Expected::Separator("]".to_string())));
+        }
         let stripped = &value[1..value.len() - 1];
         let groups = extract_groups(stripped);
-        Entry {
+        Ok(Entry {
             single: None,
-            list: Some(groups.iter().map(|x| parse_entry(x)).collect_vec()),
-        }
+            list: Some(groups.iter().map(|x| parse_entry(line, x)).collect::<Result<Vec<Entry>, ParseError>>()?),
+        })
     } else {
-        Entry {
+        Ok(Entry {
             list: None,
-            single: Some(value.parse().unwrap()),
-        }
+            single: Some(number(line, value)?),
+        })
     };
 }
 
-fn parse_set(set: &str) -> (Entry, Entry) {
-    let (first, second) = set.split_once("\n").unwrap();
-    (parse_entry(first), parse_entry(second))
+fn parse_set(set: &str) -> Result<(Entry, Entry), ParseError> {
+    let (first, second) = lines(set).collect_tuple()
+        .ok_or_else(|| ParseError::new(set, set, Expected::Format("a pair of packets on two lines".to_string())))?;
+    Ok((parse_entry(first, first)?, parse_entry(second, second).map_err(|e| e.shifted(1))?))
 }
 
-fn parse(data: &str) -> Vec<(Entry, Entry)> {
-    data.split("\n\n")
-        .map(parse_set)
-        .collect_vec()
+fn parse(data: &str) -> Result<Vec<(Entry, Entry)>, ParseError> {
+    parse_blocks(data, parse_set)
 }
 
 fn is_good_order(pair: &(Entry, Entry)) -> bool {
@@ -151,8 +151,8 @@ fn part1(entries: &Vec<(Entry, Entry)>) -> i32 {
 fn part2(entries: &Vec<(Entry, Entry)>) -> i32 {
     let left = entries.iter().map(|(x, _)| x).collect_vec();
     let right = entries.iter().map(|(_, x)| x).collect_vec();
-    let div1 = parse_entry("[[2]]");
-    let div2 = parse_entry("[[6]]");
+    let div1 = parse_entry("[[2]]", "[[2]]").unwrap();
+    let div2 = parse_entry("[[6]]", "[[6]]").unwrap();
     let divs = vec![&div1, &div2];
     let all_entries = divs.iter().chain(left.iter()).chain(right.iter()).collect_vec();
     let sorted_entries = sorted(all_entries).collect_vec();
@@ -161,9 +161,78 @@ fn part2(entries: &Vec<(Entry, Entry)>) -> i32 {
     (first + 1) as i32 * (second + 1) as i32
 }
 
-pub(crate) fn solve() {
-    let content = read_to_string("13.txt").unwrap();
-    let entries = parse(&content);
-    println!("{}", part1(&entries));
-    println!("{}", part2(&entries));
-}
\ No newline at end of file
+pub(crate) struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Entry, Entry)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Vec<(Entry, Entry)>, ParseError> {
+        parse(content)
+    }
+
+    fn part1(&self, entries: &Vec<(Entry, Entry)>) -> i32 {
+        part1(entries)
+    }
+
+    fn part2(&self, entries: &Vec<(Entry, Entry)>) -> i32 {
+        part2(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]";
+
+    fn entry(value: &str) -> Entry {
+        parse_entry(value, value).unwrap()
+    }
+
+    #[test]
+    fn part1_example() {
+        let entries = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part1(&entries), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let entries = Day13.parse(EXAMPLE).unwrap();
+        assert_eq!(Day13.part2(&entries), 140);
+    }
+
+    #[test]
+    fn entry_ordering() {
+        assert!(entry("[1,1,3,1,1]") < entry("[1,1,5,1,1]"));
+        assert!(entry("[[1],[2,3,4]]") < entry("[[1],4]"));
+        assert!(entry("[9]") > entry("[[8,7,6]]"));
+        assert!(entry("[]") < entry("[3]"));
+        assert!(entry("[[[]]]") > entry("[[]]"));
+        assert!(entry("[1]") == entry("[[1]]"));
+    }
+}
@@ -0,0 +1,189 @@
+use itertools::Itertools;
+use crate::geometry::Point;
+use crate::grid::SparseGrid;
+use crate::parse::{Expected, number, parse_lines, ParseError, split_once};
+use crate::solution::Solution;
+
+const SOURCE: Point = Point::new(500, 0);
+const FALL_DIRECTIONS: [Point; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+struct Board {
+    board: SparseGrid<char>,
+    bounds: (Point, Point),
+    added_sand: i32,
+}
+
+impl Board {
+    fn new(data: &Vec<Vec<Point>>) -> Board {
+        let mut board = SparseGrid::new();
+        for line in data {
+            for (start, end) in line.iter().tuple_windows() {
+                let step = (*end - *start).signum();
+                let mut point = *start;
+                board.insert(point, '#');
+                while point != *end {
+                    point += step;
+                    board.insert(point, '#');
+                }
+            }
+        }
+        let bounds = board.bounds().unwrap();
+        Board { board, bounds, added_sand: 0 }
+    }
+
+    fn pos_out_of_bounds(&self, position: Point) -> bool {
+        let (top_left, bottom_right) = self.bounds;
+        position.y > bottom_right.y || position.x < top_left.x || position.x > bottom_right.x
+    }
+
+    fn on_the_floor(&self, y: i32) -> bool {
+        let (_, bottom_right) = self.bounds;
+        return y == bottom_right.y + 2;
+    }
+
+    fn simple_move(&self, position: Point) -> Option<Point> {
+        let next = FALL_DIRECTIONS.iter()
+            .map(|direction| position + *direction)
+            .find(|next| !self.board.contains(*next));
+        Some(next.unwrap_or(position))
+    }
+
+    fn next_move(&self, position: Point) -> Option<Point> {
+        return if self.pos_out_of_bounds(position) {
+            None
+        } else {
+            self.simple_move(position)
+        };
+    }
+
+    fn next_move_with_floor(&self, position: Point) -> Option<Point> {
+        return if self.on_the_floor(position.y + 1) {
+            Some(position)
+        } else {
+            let next_move = self.simple_move(position);
+            return match next_move {
+                None => panic!(),
+                Some(SOURCE) => None,
+                any => any
+            };
+        };
+    }
+
+    fn mark(&mut self, pos: Point) {
+        self.board.insert(pos, 'o');
+        self.added_sand += 1;
+    }
+}
+
+impl ToString for Board {
+    fn to_string(&self) -> String {
+        let (top_left, bottom_right) = self.bounds;
+        self.board.render((top_left - Point::new(10, 5), bottom_right + Point::new(9, 2)), '.', |c| *c)
+    }
+}
+
+fn parse_coords(line: &str, coords: &str) -> Result<Point, ParseError> {
+    let (x, y) = split_once(line, coords, ",")?;
+    Ok(Point::new(number(line, x)?, number(line, y)?))
+}
+
+/// Segments have to be horizontal, vertical or diagonal, so drawing them in unit steps reaches their end.
+fn parse_line(line: &str) -> Result<Vec<Point>, ParseError> {
+    let mut points: Vec<Point> = vec![];
+    for coords in line.split(" -> ") {
+        let point = parse_coords(line, coords)?;
+        if let Some(previous) = points.last() {
+            let difference = point - *previous;
+            if difference.x != 0 && difference.y != 0 && difference.x.abs() != difference.y.abs() {
+                let expected = format!("a point in line with {},{}", previous.x, previous.y);
+                return Err(ParseError::new(line, coords, Expected::Format(expected)));
+            }
+        }
+        points.push(point);
+    }
+    Ok(points)
+}
+
+fn parse_data(data: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+    parse_lines(data, parse_line)
+}
+
+
+fn play(mut board: Board, next_move: fn(&Board, Point) -> Option<Point>) -> i32 {
+    loop {
+        let mut pos = SOURCE;
+        loop {
+            let new_pos = next_move(&board, pos);
+            match new_pos {
+                None => {
+                    return board.added_sand;
+                }
+                Some(new_position) => {
+                    if new_position == pos {
+                        board.mark(pos);
+                        break;
+                    } else {
+                        pos = new_position
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn part1(board: Board) -> i32 {
+    play(board, Board::next_move)
+}
+
+fn part2(board: Board) -> i32 {
+    play(board, Board::next_move_with_floor) + 1
+}
+
+pub(crate) struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Point>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+        parse_data(content)
+    }
+
+    fn part1(&self, data: &Vec<Vec<Point>>) -> i32 {
+        part1(Board::new(data))
+    }
+
+    fn part2(&self, data: &Vec<Vec<Point>>) -> i32 {
+        part2(Board::new(data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn part1_example() {
+        let data = Day14.parse(EXAMPLE).unwrap();
+        assert_eq!(Day14.part1(&data), 24);
+    }
+
+    #[test]
+    fn skewed_segment_is_reported() {
+        let error = Day14.parse("0,0 -> 0,2\n0,0 -> 2,1").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 8, "2,1"));
+        assert_eq!(error.expected, Expected::Format("a point in line with 0,0".to_string()));
+        assert!(Day14.parse("0,0 -> 2,2 -> 2,0").is_ok());
+    }
+
+    #[test]
+    fn part2_example() {
+        let data = Day14.parse(EXAMPLE).unwrap();
+        assert_eq!(Day14.part2(&data), 93);
+    }
+}
@@ -0,0 +1,228 @@
+use itertools::Itertools;
+use regex::Regex;
+use crate::geometry::Point;
+use crate::interval::{Interval, IntervalSet};
+use crate::parse::{Expected, number, parse_lines, ParseError};
+use crate::solution::{Maybe, parameter, Solution, unknown_parameter};
+
+pub(crate) struct Sensor {
+    sensor: Point,
+    beacon: Point,
+    max_distance: i32,
+}
+
+impl Sensor {
+    /// Columns of row `y` within range of the sensor.
+    pub(crate) fn covered_columns(&self, y: i32) -> Option<Interval> {
+        let steps_left_to_do = self.max_distance - (self.sensor.y - y).abs();
+        return if steps_left_to_do >= 0 {
+            let left = self.sensor.x - steps_left_to_do;
+            let right = self.sensor.x + steps_left_to_do;
+            Some(Interval::new(left as i64, right as i64))
+        } else {
+            None
+        };
+    }
+}
+
+impl Sensor {
+    fn new(line: &str) -> Result<Sensor, ParseError> {
+        let pattern = Regex::new(r"-?\d+").unwrap();
+        let (s_x, s_y, b_x, b_y) = pattern.find_iter(line)
+            .map(|m| number(line, m.as_str()))
+            .collect::<Result<Vec<i32>, ParseError>>()?
+            .into_iter()
+            .collect_tuple()
+            .ok_or_else(|| ParseError::new(line, line, Expected::Format("Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>".to_string())))?;
+        let sensor = Point::new(s_x, s_y);
+        let beacon = Point::new(b_x, b_y);
+        let max_distance = sensor.manhattan(&beacon);
+        Ok(Sensor {
+            sensor,
+            beacon,
+            max_distance,
+        })
+    }
+
+    /// Constants `c` of the lines `x - y = c` and `x + y = c` running just outside of the sensor range.
+    fn boundaries(&self) -> ([i64; 2], [i64; 2]) {
+        let (x, y, d) = (self.sensor.x as i64, self.sensor.y as i64, self.max_distance as i64 + 1);
+        ([x - y - d, x - y + d], [x + y - d, x + y + d])
+    }
+}
+
+fn parse_sensors(data: &str) -> Result<Vec<Sensor>, ParseError> {
+    parse_lines(data, Sensor::new)
+}
+
+/// Columns of row `y` within range of any sensor.
+fn coverage(sensors: &[Sensor], y: i32) -> IntervalSet {
+    sensors.iter()
+        .filter_map(|s| s.covered_columns(y))
+        .collect()
+}
+
+/// Rows within `0..=area` the distress beacon can be in. An uncovered position bordered by covered ones lies just
+/// outside the range of a sensor, on one of its boundary diagonals: where two of them cross, where one meets the
+/// left or right edge of the area, or in the top or bottom row.
+fn candidate_rows(sensors: &[Sensor], area: i32) -> IntervalSet {
+    let area = area as i64;
+    let (rising, falling): (Vec<[i64; 2]>, Vec<[i64; 2]>) = sensors.iter().map(|s| s.boundaries()).unzip();
+    let crossings: IntervalSet = rising.iter().flatten()
+        .cartesian_product(falling.iter().flatten())
+        .filter(|(a, b)| (*b - *a) % 2 == 0)
+        .map(|(a, b)| Interval::new((b - a) / 2, (b - a) / 2))
+        .collect();
+    let edges: IntervalSet = rising.iter().flatten().flat_map(|a| [-a, area - a])
+        .chain(falling.iter().flatten().flat_map(|b| [*b, b - area]))
+        .chain([0, area])
+        .map(|y| Interval::new(y, y))
+        .collect();
+    crossings.union(&edges).intersection(&IntervalSet::from_iter([Interval::new(0, area)]))
+}
+
+/// The first position within `0..=area` in both coordinates that no sensor covers, searched in the candidate rows only.
+fn distress_beacon(sensors: &[Sensor], area: i32) -> Option<Point> {
+    let bounds = Interval::new(0, area as i64);
+    candidate_rows(sensors, area).intervals().iter()
+        .flat_map(|rows| rows.start..=rows.end)
+        .map(|y| (y, coverage(sensors, y as i32).gaps(bounds)))
+        .find(|(_, gaps)| !gaps.is_empty())
+        .map(|(y, gaps)| Point::new(gaps.intervals()[0].start as i32, y as i32))
+}
+
+/// Tuning frequency of the distress beacon, `None` when every position of the area is covered.
+fn part2(sensors: &Vec<Sensor>, area: i32, multiplier: i64) -> Option<i64> {
+    let beacon = distress_beacon(sensors, area)?;
+    Some(beacon.x as i64 * multiplier + beacon.y as i64)
+}
+
+/// Number of positions in row `y` where no beacon can be, known beacons excluded.
+fn covered_positions(sensors: &[Sensor], y: i32) -> i64 {
+    let covered = coverage(sensors, y);
+    let beacons = sensors.iter()
+        .map(|s| s.beacon)
+        .filter(|beacon| beacon.y == y && covered.contains(beacon.x as i64))
+        .unique()
+        .count();
+    covered.len() - beacons as i64
+}
+
+pub(crate) struct Day15 {
+    /// Row checked in part 1.
+    pub(crate) row: i32,
+    /// Largest coordinate the distress beacon can have, the smallest is 0.
+    pub(crate) area: i32,
+    /// Factor of the x coordinate in the tuning frequency.
+    pub(crate) multiplier: i64,
+}
+
+impl Default for Day15 {
+    fn default() -> Day15 {
+        Day15 { row: 2000000, area: 4000000, multiplier: 4000000 }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Vec<Sensor>;
+    type Output1 = i64;
+    type Output2 = Maybe<i64>;
+
+    fn parse(&self, content: &str) -> Result<Vec<Sensor>, ParseError> {
+        parse_sensors(content)
+    }
+
+    fn part1(&self, sensors: &Vec<Sensor>) -> i64 {
+        covered_positions(sensors, self.row)
+    }
+
+    fn part2(&self, sensors: &Vec<Sensor>) -> Maybe<i64> {
+        Maybe(part2(sensors, self.area, self.multiplier))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "row" => self.row = parameter(key, value)?,
+            "area" => self.area = parameter(key, value)?,
+            "multiplier" => self.multiplier = parameter(key, value)?,
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn sensor_distances() {
+        let sensors = Day15::default().parse(EXAMPLE).unwrap();
+        assert_eq!(sensors.len(), 14);
+        assert_eq!(sensors[0].max_distance, 7);
+        assert_eq!(sensors[6].max_distance, 9);
+    }
+
+    #[test]
+    fn impossible_columns_in_row() {
+        let sensors = Day15::default().parse(EXAMPLE).unwrap();
+        assert_eq!(sensors[6].covered_columns(10), Some(Interval::new(2, 14)));
+        assert_eq!(sensors[6].covered_columns(-2), Some(Interval::new(8, 8)));
+        assert_eq!(sensors[6].covered_columns(-3), None);
+    }
+
+    #[test]
+    fn covered_positions_in_example_row() {
+        let sensors = Day15::default().parse(EXAMPLE).unwrap();
+        assert_eq!(covered_positions(&sensors, 10), 26);
+    }
+
+    #[test]
+    fn part2_example() {
+        let day = Day15 { row: 10, area: 20, ..Day15::default() };
+        let sensors = day.parse(EXAMPLE).unwrap();
+        assert_eq!(distress_beacon(&sensors, 20), Some(Point::new(14, 11)));
+        assert_eq!(day.part1(&sensors), 26);
+        assert_eq!(day.part2(&sensors), Maybe(Some(56000011)));
+    }
+
+    #[test]
+    fn covered_area_has_no_distress_beacon() {
+        let day = Day15 { area: 3, ..Day15::default() };
+        let sensors = day.parse("Sensor at x=1, y=1: closest beacon is at x=5, y=1").unwrap();
+        assert_eq!(day.part2(&sensors).to_string(), "-");
+    }
+
+    #[test]
+    fn distress_beacon_is_out_of_range() {
+        let sensors = Day15::default().parse(EXAMPLE).unwrap();
+        assert!(!coverage(&sensors, 11).contains(14));
+        assert!(coverage(&sensors, 10).contains(14));
+    }
+
+    #[test]
+    fn distress_beacon_on_the_edge() {
+        let sensors = Day15::default().parse("\
+Sensor at x=7, y=-2: closest beacon is at x=7, y=4
+Sensor at x=-2, y=9: closest beacon is at x=-2, y=6
+Sensor at x=-1, y=3: closest beacon is at x=5, y=3
+Sensor at x=5, y=8: closest beacon is at x=5, y=4").unwrap();
+        assert_eq!(distress_beacon(&sensors, 6), Some(Point::new(6, 4)));
+    }
+}
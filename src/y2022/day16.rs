@@ -1,8 +1,9 @@
 use std::cmp::min;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 use itertools::Itertools;
 use regex::Regex;
+use crate::parse::{Expected, lines, number, parse_lines, ParseError};
+use crate::solution::{parameter, Solution, unknown_parameter};
 
 fn floyd_warshall(graph: &HashMap<String, HashSet<String>>) -> HashMap<(&String, &String), i32> {
     let nodes = graph.keys().collect_vec();
@@ -33,26 +34,26 @@ fn floyd_warshall(graph: &HashMap<String, HashSet<String>>) -> HashMap<(&String,
     distances
 }
 
-fn parse_line(line: &str) -> (String, i32, HashSet<String>) {
+fn parse_line(line: &str) -> Result<(String, i32, HashSet<String>), ParseError> {
     let pattern = Regex::new(r"Valve (.*) has flow rate=(\d+); tunnel.? lead.? to valve.? (.*)").unwrap();
-    let c = pattern.captures(line).unwrap();
+    let c = pattern.captures(line)
+        .ok_or_else(|| ParseError::new(line, line, Expected::Format("Valve <name> has flow rate=<rate>; tunnels lead to valves <names>".to_string())))?;
     let neighbours: HashSet<String> = c.get(3).unwrap().as_str().split(", ")
         .map(|x| x.to_string())
         .collect();
-    (c.get(1).unwrap().as_str().to_string(), c.get(2).unwrap().as_str().parse().unwrap(), neighbours)
+    Ok((c.get(1).unwrap().as_str().to_string(), number(line, c.get(2).unwrap().as_str())?, neighbours))
 }
 
-fn parse_graph(data: &str) -> (HashMap<String, HashSet<String>>, HashMap<String, i32>) {
+fn parse_graph(data: &str) -> Result<(HashMap<String, HashSet<String>>, HashMap<String, i32>), ParseError> {
     let mut graph = HashMap::new();
     let mut flow = HashMap::new();
-    for line in data.split("\n") {
-        let (name, rate, neighbours) = parse_line(line);
+    for (name, rate, neighbours) in parse_lines(data, parse_line)? {
         if rate > 0 {
             flow.insert(name.clone(), rate);
         }
         graph.insert(name.clone(), neighbours);
     }
-    (graph, flow)
+    Ok((graph, flow))
 }
 
 fn find_path(nodes: &HashSet<&String>, flows: &HashMap<String, i32>, distances: &HashMap<(&String, &String), i32>, steps_left: i32, visited: &HashSet<&String>, current: &String, score: i32, rate: i32) -> i32 {
@@ -121,20 +122,115 @@ fn find_dual_path(nodes: &HashSet<&String>, flows: &HashMap<String, i32>, distan
     };
 }
 
-fn part2(flows: &HashMap<String, i32>, distances: &HashMap<(&String, &String), i32>) -> i32 {
+fn part2(flows: &HashMap<String, i32>, distances: &HashMap<(&String, &String), i32>, minutes: i32, start: &String) -> i32 {
     let nodes: HashSet<&String> = flows.keys().collect();
-    find_dual_path(&nodes, flows, distances, 26, 26, &HashSet::new(), &"AA".to_string(), &"AA".to_string(), 0, 0, 0, 0)
+    find_dual_path(&nodes, flows, distances, minutes, minutes, &HashSet::new(), start, start, 0, 0, 0, 0)
 }
 
-fn part1(flows: &HashMap<String, i32>, distances: &HashMap<(&String, &String), i32>) -> i32 {
+fn part1(flows: &HashMap<String, i32>, distances: &HashMap<(&String, &String), i32>, minutes: i32, start: &String) -> i32 {
     let nodes: HashSet<&String> = flows.keys().collect();
-    find_path(&nodes, flows, distances, 30, &HashSet::new(), &"AA".to_string(), 0, 0)
+    find_path(&nodes, flows, distances, minutes, &HashSet::new(), start, 0, 0)
 }
 
-pub(crate) fn solve() {
-    let content = read_to_string("16.txt").unwrap();
-    let (graph, flows) = parse_graph(&content);
-    let distances = floyd_warshall(&graph);
-    println!("{}", part1(&flows, &distances));
-    println!("{}", part2(&flows, &distances));
-}
\ No newline at end of file
+pub(crate) struct Day16 {
+    /// Minutes before the volcano erupts when working alone.
+    pub(crate) minutes: i32,
+    /// Minutes left after teaching the elephant.
+    pub(crate) minutes_with_elephant: i32,
+    /// Valve everybody starts at.
+    pub(crate) start: String,
+}
+
+impl Default for Day16 {
+    fn default() -> Day16 {
+        Day16 { minutes: 30, minutes_with_elephant: 26, start: "AA".to_string() }
+    }
+}
+
+impl Solution for Day16 {
+    type Input = (HashMap<String, HashSet<String>>, HashMap<String, i32>);
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Self::Input, ParseError> {
+        let (graph, flows) = parse_graph(content)?;
+        if !graph.contains_key(&self.start) {
+            return Err(ParseError::new("", "", Expected::Format(format!("a line for the start valve {}", self.start)))
+                .shifted(lines(content).count()));
+        }
+        Ok((graph, flows))
+    }
+
+    fn part1(&self, (graph, flows): &Self::Input) -> i32 {
+        let distances = floyd_warshall(graph);
+        part1(flows, &distances, self.minutes, &self.start)
+    }
+
+    fn part2(&self, (graph, flows): &Self::Input) -> i32 {
+        let distances = floyd_warshall(graph);
+        part2(flows, &distances, self.minutes_with_elephant, &self.start)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "minutes" => self.minutes = parameter(key, value)?,
+            "minutes_with_elephant" => self.minutes_with_elephant = parameter(key, value)?,
+            "start" => self.start = value.to_string(),
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II";
+
+    #[test]
+    fn unknown_start_is_reported() {
+        let day = Day16 { start: "ZZ".to_string(), ..Day16::default() };
+        let error = day.parse(EXAMPLE).err().unwrap();
+        assert_eq!(error.line, 11);
+    }
+
+    #[test]
+    fn part1_example() {
+        let input = Day16::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::default().part1(&input), 1651);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day16::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day16::default().part2(&input), 1707);
+    }
+
+    #[test]
+    fn fewer_minutes() {
+        let day = Day16 { minutes: 3, ..Day16::default() };
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&input), 20);
+    }
+
+    #[test]
+    fn shortest_distances() {
+        let (graph, _) = Day16::default().parse(EXAMPLE).unwrap();
+        let distances = floyd_warshall(&graph);
+        let (aa, bb, hh, jj) = ("AA".to_string(), "BB".to_string(), "HH".to_string(), "JJ".to_string());
+        assert_eq!(distances.get(&(&aa, &bb)), Some(&1));
+        assert_eq!(distances.get(&(&aa, &hh)), Some(&5));
+        assert_eq!(distances.get(&(&jj, &hh)), Some(&7));
+    }
+}
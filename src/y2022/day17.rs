@@ -0,0 +1,362 @@
+use std::cmp::max;
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::bench::Format;
+use crate::cycle::detect;
+use crate::geometry::{Direction, Point};
+use crate::parse::{Expected, ParseError};
+use crate::solution::{parameter, Solution, unknown_parameter};
+
+/// Heights in the chamber grow upwards, so falling decreases `y`.
+const FALL: Point = Point::new(0, -1);
+
+#[derive(Clone)]
+struct Block {
+    bottom_left: Point,
+    other_parts: Vec<Point>,
+}
+
+impl Block {
+    fn taken_spots(&self) -> Vec<Point> {
+        self.other_parts
+            .iter()
+            .map(|part| self.bottom_left + *part)
+            .collect_vec()
+    }
+
+    fn next_position(&self, vector: Point) -> Block {
+        Block { bottom_left: self.bottom_left + vector, other_parts: self.other_parts.clone() }
+    }
+
+    fn is_out_of_bounds(&self) -> bool {
+        self.taken_spots()
+            .iter()
+            .any(|spot| (spot.x < 0) | (spot.x > 6))
+    }
+}
+
+struct Board {
+    taken_spaces: HashSet<Point>,
+    highest_point: i32,
+    column_heights: [i32; 7],
+    moves: Vec<Direction>,
+    current_move: usize,
+    block_to_spawn: usize,
+    added_blocks: usize,
+    shapes: Vec<Vec<Point>>,
+}
+
+impl Board {
+    fn spawn_new_block(&mut self) -> Block {
+        let y = self.highest_point + 4;
+        let x = 2;
+        let shape = self.shapes.get(self.block_to_spawn).unwrap();
+        self.added_blocks += 1;
+        self.block_to_spawn = self.added_blocks % (self.shapes.len());
+        Block {
+            bottom_left: Point::new(x, y),
+            other_parts: shape.clone(),
+        }
+    }
+
+    fn add_block_to_board(&mut self, block: &Block) {
+        for spot in block.taken_spots() {
+            if spot.y > self.highest_point {
+                self.highest_point = spot.y;
+            }
+            let column = &mut self.column_heights[spot.x as usize];
+            *column = max(*column, spot.y);
+            self.taken_spaces.insert(spot);
+        }
+    }
+
+    /// Everything the next rocks depend on: the next jet, the next shape and how deep every column is below the top.
+    fn state_key(&self) -> (usize, usize, [i32; 7]) {
+        (self.current_move, self.block_to_spawn, self.column_heights.map(|height| self.highest_point - height))
+    }
+
+    fn next_position(&mut self, block: &Block, round_number: i64) -> Option<Block> {
+        return if round_number % 2 == 0 {
+            let direction = self.moves.get(self.current_move).unwrap();
+            self.current_move = (self.current_move + 1) % self.moves.len();
+            let b = block.next_position(direction.vector());
+            if b.is_out_of_bounds() | self.collides(&b) {
+                Some(block.clone())
+            } else {
+                Some(b)
+            }
+        } else {
+            let b = block.next_position(FALL);
+            if b.is_out_of_bounds() | self.collides(&b) {
+                None
+            } else {
+                Some(b)
+            }
+        };
+    }
+
+    fn collides(&self, block: &Block) -> bool {
+        block.taken_spots()
+            .iter()
+            .any(|x| self.taken_spaces.contains(x))
+    }
+
+    /// Moves `block` until it comes to rest, `observe` sees the board and the block after every jet push and fall.
+    fn move_block(&mut self, block: &Block, round_number: i64, observe: &mut impl FnMut(&Board, &Block, &str)) -> i64 {
+        let mut current_block = block.clone();
+        let mut round = round_number;
+        loop {
+            let jet = self.moves[self.current_move];
+            let new_block = self.next_position(&current_block, round);
+            let moved = new_block.as_ref().map_or(false, |b| b.bottom_left != current_block.bottom_left);
+            let event = match (round % 2, jet, moved) {
+                (0, Direction::West, true) => "Jet of gas pushes rock left",
+                (0, Direction::West, false) => "Jet of gas pushes rock left, but the rock is blocked",
+                (0, _, true) => "Jet of gas pushes rock right",
+                (0, _, false) => "Jet of gas pushes rock right, but the rock is blocked",
+                _ => "Rock falls 1 unit"
+            };
+            round += 1;
+            match new_block {
+                Some(b) => {
+                    current_block = b;
+                    observe(self, &current_block, event);
+                }
+                None => {
+                    self.add_block_to_board(&current_block);
+                    return round;
+                }
+            }
+        }
+    }
+
+    fn add_new_block(&mut self, round_number: i64) -> i64 {
+        self.add_observed_block(round_number, &mut |_, _, _| {})
+    }
+
+    fn add_observed_block(&mut self, round_number: i64, observe: &mut impl FnMut(&Board, &Block, &str)) -> i64 {
+        let block = self.spawn_new_block();
+        observe(self, &block, "A new rock begins falling");
+        self.move_block(&block, round_number, observe)
+    }
+
+    /// The top `rows` rows of the chamber drawn like in the puzzle, `@` marks the `falling` rock.
+    fn render(&self, falling: Option<&Block>, rows: usize) -> String {
+        let falling = falling.map(|block| block.taken_spots()).unwrap_or_default();
+        let top = falling.iter().map(|spot| spot.y).fold(self.highest_point, max);
+        let bottom = max(1, top - rows as i32 + 1);
+        let mut lines = (bottom..=top).rev()
+            .map(|y| {
+                let row = (0..7)
+                    .map(|x| Point::new(x, y))
+                    .map(|spot| match (falling.contains(&spot), self.taken_spaces.contains(&spot)) {
+                        (true, _) => '@',
+                        (_, true) => '#',
+                        _ => '.'
+                    })
+                    .collect::<String>();
+                format!("|{}|", row)
+            })
+            .collect_vec();
+        if bottom == 1 {
+            lines.push("+-------+".to_string());
+        }
+        lines.join("\n")
+    }
+
+    fn new(moves: &Vec<Direction>, shapes: &Vec<Vec<Point>>) -> Board {
+        let taken_spaces = HashSet::from_iter((0..7).map(|x| Point::new(x, 0)));
+        Board {
+            taken_spaces,
+            highest_point: 0,
+            column_heights: [0; 7],
+            moves: moves.clone(),
+            current_move: 0,
+            block_to_spawn: 0,
+            added_blocks: 0,
+            shapes: shapes.clone(),
+        }
+    }
+}
+
+fn part1(moves: &Vec<Direction>, shapes: &Vec<Vec<Point>>, rocks: usize) -> i64 {
+    let mut board = Board::new(moves, shapes);
+    let mut round_number = 0;
+    for _ in 0..rocks {
+        round_number = board.add_new_block(round_number)
+    }
+    board.highest_point as i64
+}
+
+fn part2(moves: &Vec<Direction>, shapes: &Vec<Vec<Point>>, rocks: usize) -> i64 {
+    let mut state = (Board::new(moves, shapes), 0);
+    let history = detect(&mut state, rocks,
+                         |(board, round)| *round = board.add_new_block(*round),
+                         |(board, _)| board.state_key(),
+                         |(board, _)| board.highest_point as i64);
+    history.value_at(rocks).unwrap()
+}
+
+/// The chamber after each of the first `rocks` rocks came to rest, with `jets` also after every push and fall.
+fn replay(moves: &Vec<Direction>, shapes: &Vec<Vec<Point>>, rocks: usize, rows: usize, jets: bool) -> String {
+    let mut board = Board::new(moves, shapes);
+    let mut frames = vec![];
+    let mut round_number = 0;
+    for rock in 1..=rocks {
+        round_number = board.add_observed_block(round_number, &mut |board, block, event| {
+            if jets {
+                frames.push(format!("{}:\n{}", event, board.render(Some(block), rows)));
+            }
+        });
+        frames.push(format!("Rock {} comes to rest, tower height {}:\n{}", rock, board.highest_point, board.render(None, rows)));
+    }
+    frames.join("\n\n")
+}
+
+fn shapes() -> Vec<Vec<Point>> {
+    [
+        vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+        vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+        vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+        vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+        vec![(0, 0), (0, 1), (1, 0), (1, 1)],
+    ]
+        .into_iter()
+        .map(|shape| shape.into_iter().map(|(x, y)| Point::new(x, y)).collect_vec())
+        .collect_vec()
+}
+
+pub(crate) struct Day17 {
+    /// Rocks dropped in part 1.
+    pub(crate) rocks: usize,
+    /// Rocks dropped in part 2, too many to simulate one by one.
+    pub(crate) many_rocks: usize,
+    /// Rows of the top of the chamber shown by the report.
+    pub(crate) rows: usize,
+    /// Rocks the report replays one by one, 0 only shows the tower after part 1.
+    pub(crate) replay: usize,
+    /// Whether the replay also shows every jet push and fall instead of only resting rocks.
+    pub(crate) jets: bool,
+}
+
+impl Default for Day17 {
+    fn default() -> Day17 {
+        Day17 { rocks: 2022, many_rocks: 1000000000000, rows: 20, replay: 0, jets: false }
+    }
+}
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, content: &str) -> Result<Vec<Direction>, ParseError> {
+        match content.char_indices().find(|(_, c)| *c != '<' && *c != '>') {
+            Some((index, _)) => Err(ParseError::new(content, &content[index..], Expected::OneOf("< or >".to_string()))),
+            None if content.is_empty() => Err(ParseError::new(content, content, Expected::OneOf("< or >".to_string()))),
+            None => Ok(content.chars().filter_map(Direction::from_arrow).collect_vec())
+        }
+    }
+
+    fn part1(&self, moves: &Vec<Direction>) -> i64 {
+        part1(moves, &shapes(), self.rocks)
+    }
+
+    fn part2(&self, moves: &Vec<Direction>) -> i64 {
+        part2(moves, &shapes(), self.many_rocks)
+    }
+
+    fn report(&self, moves: &Vec<Direction>, _colour: bool, _format: Format) -> Option<String> {
+        return if self.replay > 0 {
+            Some(replay(moves, &shapes(), self.replay, self.rows, self.jets))
+        } else {
+            let mut board = Board::new(moves, &shapes());
+            let mut round_number = 0;
+            for _ in 0..self.rocks {
+                round_number = board.add_new_block(round_number);
+            }
+            Some(format!("Top of the tower after {} rocks:\n{}", self.rocks, board.render(None, self.rows)))
+        };
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "rocks" => self.rocks = parameter(key, value)?,
+            "many_rocks" => self.many_rocks = parameter(key, value)?,
+            "rows" => self.rows = parameter(key, value)?,
+            "replay" => self.replay = parameter(key, value)?,
+            "jets" => self.jets = parameter(key, value)?,
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn part1_example() {
+        let moves = Day17::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::default().part1(&moves), 3068);
+    }
+
+    #[test]
+    fn part2_example() {
+        let moves = Day17::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day17::default().part2(&moves), 1514285714288);
+    }
+
+    #[test]
+    fn cycle_matches_simulation() {
+        let day = Day17 { rocks: 5000, many_rocks: 5000, ..Day17::default() };
+        let moves = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part2(&moves), day.part1(&moves));
+    }
+
+    #[test]
+    fn chamber_after_two_rocks() {
+        let moves = Day17::default().parse(EXAMPLE).unwrap();
+        let frames = replay(&moves, &shapes(), 2, 10, false);
+        assert_eq!(frames, "\
+Rock 1 comes to rest, tower height 1:
+|..####.|
++-------+
+
+Rock 2 comes to rest, tower height 4:
+|...#...|
+|..###..|
+|...#...|
+|..####.|
++-------+");
+    }
+
+    #[test]
+    fn replay_shows_falling_rock() {
+        let moves = Day17::default().parse(EXAMPLE).unwrap();
+        let frames = replay(&moves, &shapes(), 1, 4, true);
+        assert!(frames.starts_with("A new rock begins falling:\n|..@@@@.|\n|.......|\n|.......|\n|.......|\n+-------+\n\n"));
+        assert!(frames.contains("Jet of gas pushes rock right:\n|...@@@@|"));
+        assert!(frames.contains("Jet of gas pushes rock left:\n|..@@@@.|"));
+        assert!(frames.contains("Jet of gas pushes rock right, but the rock is blocked:\n|...@@@@|"));
+    }
+
+    #[test]
+    fn jets_are_required() {
+        let error = Day17::default().parse("").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (1, 1, ""));
+        assert!(Day17::default().parse("<>x").is_err());
+    }
+
+    #[test]
+    fn first_block_lands_on_floor() {
+        let moves = Day17::default().parse(EXAMPLE).unwrap();
+        let mut board = Board::new(&moves, &shapes());
+        board.add_new_block(0);
+        assert_eq!(board.highest_point, 1);
+        assert!((2..6).all(|x| board.taken_spaces.contains(&Point::new(x, 1))));
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::geometry::Point3;
+use crate::parse::{Expected, number, parse_lines, ParseError};
+use crate::search::{self, bfs};
+use crate::solution::Solution;
+
+fn parse_cube(line: &str) -> Result<Point3, ParseError> {
+    line.split(",")
+        .map(|x| number(line, x))
+        .collect::<Result<Vec<i32>, ParseError>>()?
+        .into_iter()
+        .collect_tuple()
+        .map(|(x, y, z)| Point3::new(x, y, z))
+        .ok_or_else(|| ParseError::new(line, line, Expected::Format("x,y,z".to_string())))
+}
+
+fn parse_input(content: &str) -> Result<Vec<Point3>, ParseError> {
+    parse_lines(content, parse_cube)
+}
+
+/// Smallest and largest coordinate of the scan extended by one, so the air around the droplet stays connected.
+fn bounds(cubes: &Vec<Point3>) -> (i32, i32) {
+    cubes.iter()
+        .flat_map(|c| [c.x, c.y, c.z])
+        .minmax()
+        .into_option()
+        .map(|(min, max)| (min - 1, max + 1))
+        .unwrap_or((0, 0))
+}
+
+fn neighbouring_cubes(cube: &Point3, (min, max): (i32, i32)) -> Vec<Point3> {
+    cube.neighbours6()
+        .into_iter()
+        .filter(|c| [c.x, c.y, c.z].iter().all(|v| (min..=max).contains(v)))
+        .collect_vec()
+}
+
+fn uncovered_sides(cube: &Point3, taken_slots: &HashSet<&Point3>, bounds: (i32, i32)) -> Vec<Point3> {
+    neighbouring_cubes(cube, bounds)
+        .iter()
+        .filter(|&c| !taken_slots.contains(c))
+        .map(|x| *x)
+        .collect_vec()
+}
+
+/// Air around the droplet, within the bounding box of the scan extended by one in every direction.
+struct Air<'a> {
+    taken_slots: &'a HashSet<&'a Point3>,
+    bounds: (i32, i32),
+}
+
+impl search::Graph for Air<'_> {
+    type Node = Point3;
+
+    fn neighbours(&self, cube: &Point3) -> Vec<(Point3, u64)> {
+        uncovered_sides(cube, self.taken_slots, self.bounds)
+            .into_iter()
+            .map(|neighbour| (neighbour, 1))
+            .collect_vec()
+    }
+}
+
+fn external_cubes(cooling_cubes: &Vec<Point3>, taken_slots: &HashSet<&Point3>, bounds: (i32, i32)) -> HashSet<Point3> {
+    let (min, _) = bounds;
+    let reachable = bfs(&Air { taken_slots, bounds }, [Point3::new(min, min, min)], |_| false);
+    cooling_cubes
+        .iter()
+        .filter(|&c| reachable.contains(c))
+        .map(|c| *c)
+        .collect()
+}
+
+fn part2(cubes: &Vec<Point3>) -> usize {
+    let taken_slots = HashSet::from_iter(cubes);
+    let bounds = bounds(cubes);
+    let cooling_cubes = cubes.iter()
+        .flat_map(|&cube| uncovered_sides(&cube, &taken_slots, bounds))
+        .collect_vec();
+    let external = external_cubes(&cooling_cubes, &taken_slots, bounds);
+    cooling_cubes.iter()
+        .filter(|&c| external.contains(c))
+        .count()
+}
+
+fn part1(cubes: &Vec<Point3>) -> usize {
+    let taken_slots = HashSet::from_iter(cubes);
+    let bounds = bounds(cubes);
+    cubes.iter()
+        .flat_map(|&cube| uncovered_sides(&cube, &taken_slots, bounds))
+        .count()
+}
+
+pub(crate) struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Point3>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, content: &str) -> Result<Vec<Point3>, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(&self, cubes: &Vec<Point3>) -> usize {
+        part1(cubes)
+    }
+
+    fn part2(&self, cubes: &Vec<Point3>) -> usize {
+        part2(cubes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    #[test]
+    fn part1_example() {
+        let cubes = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(Day18.part1(&cubes), 64);
+    }
+
+    #[test]
+    fn part2_example() {
+        let cubes = Day18.parse(EXAMPLE).unwrap();
+        assert_eq!(Day18.part2(&cubes), 58);
+    }
+
+    #[test]
+    fn cubes_beyond_the_old_bounding_box() {
+        let cubes = Day18.parse("30,30,30\n31,30,30\n-5,0,0").unwrap();
+        assert_eq!(Day18.part1(&cubes), 16);
+        assert_eq!(Day18.part2(&cubes), 16);
+    }
+
+    #[test]
+    fn two_adjacent_cubes() {
+        let cubes = Day18.parse("1,1,1\n2,1,1").unwrap();
+        assert_eq!(Day18.part1(&cubes), 10);
+        assert_eq!(Day18.part2(&cubes), 10);
+    }
+}
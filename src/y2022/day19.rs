@@ -1,17 +1,18 @@
-use std::fs::read_to_string;
 use itertools::Itertools;
 use regex::Regex;
+use crate::parse::{Expected, number, parse_lines, ParseError};
+use crate::solution::{parameter, Solution, unknown_parameter};
 
-struct Blueprint {
-    ore_ore: i8,
-    clay_ore: i8,
-    obsidian_ore: i8,
-    obsidian_clay: i8,
-    geode_ore: i8,
-    geode_obsidian: i8,
+pub(crate) struct Blueprint {
+    ore_ore: i32,
+    clay_ore: i32,
+    obsidian_ore: i32,
+    obsidian_clay: i32,
+    geode_ore: i32,
+    geode_obsidian: i32,
 }
 
-fn ceil(x: i8, y: i8) -> i8 {
+fn ceil(x: i32, y: i32) -> i32 {
     let z = x / y;
     let v = x % y;
     return if z < 0 {
@@ -24,22 +25,24 @@ fn ceil(x: i8, y: i8) -> i8 {
 }
 
 impl Blueprint {
-    fn new(line: &str) -> Blueprint {
+    fn new(line: &str) -> Result<Blueprint, ParseError> {
         let pattern = Regex::new(r"\d+").unwrap();
         let (_, ore_ore, clay_ore, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian) = pattern.find_iter(line)
-            .map(|m| m.as_str().parse().unwrap())
+            .map(|m| number(line, m.as_str()))
+            .collect::<Result<Vec<i32>, ParseError>>()?
+            .into_iter()
             .collect_tuple()
-            .unwrap();
-        Blueprint {
+            .ok_or_else(|| ParseError::new(line, line, Expected::Format("a blueprint with an id and 6 robot costs".to_string())))?;
+        Ok(Blueprint {
             ore_ore,
             clay_ore,
             obsidian_ore,
             obsidian_clay,
             geode_ore,
             geode_obsidian,
-        }
+        })
     }
-    fn produce_ore_robot(&self, state: &State) -> Option<(State, i8)> {
+    fn produce_ore_robot(&self, state: &State) -> Option<(State, i32)> {
         return if (state.ore_robots > 3) | (state.ore > 4) {
             None
         } else if state.ore >= self.ore_ore {
@@ -61,7 +64,7 @@ impl Blueprint {
         };
     }
 
-    fn produce_clay_robot(&self, state: &State) -> Option<(State, i8)> {
+    fn produce_clay_robot(&self, state: &State) -> Option<(State, i32)> {
         return if (state.ore_robots > 6) | (state.clay > 25) {
             None
         } else if state.ore >= self.clay_ore {
@@ -83,7 +86,7 @@ impl Blueprint {
         };
     }
 
-    fn produce_obsidian_robot(&self, state: &State) -> Option<(State, i8)> {
+    fn produce_obsidian_robot(&self, state: &State) -> Option<(State, i32)> {
         return if (state.obsidian_robots > 6) | (state.obsidian > 25) {
             None
         } else if (state.ore >= self.obsidian_ore) & (state.clay >= self.obsidian_clay) {
@@ -110,7 +113,7 @@ impl Blueprint {
         };
     }
 
-    fn produce_geode_robot(&self, state: &State) -> Option<(State, i8)> {
+    fn produce_geode_robot(&self, state: &State) -> Option<(State, i32)> {
         if (state.ore >= self.geode_ore) & (state.obsidian >= self.geode_obsidian) {
             let mut s = State::make_from(&state.progress_one_round());
             s.ore -= self.geode_ore;
@@ -135,7 +138,7 @@ impl Blueprint {
         }
     }
 
-    fn generate_possible_robots(&self, state: &State, minutes: &i8) -> Vec<(State, i8)> {
+    fn generate_possible_robots(&self, state: &State, minutes: &i32) -> Vec<(State, i32)> {
         vec![self.produce_geode_robot(state), self.produce_obsidian_robot(state), self.produce_clay_robot(state), self.produce_ore_robot(state)]
             .iter()
             .filter(|&x| x.is_some())
@@ -148,14 +151,14 @@ impl Blueprint {
 
 #[derive(Clone)]
 struct State {
-    ore: i8,
-    ore_robots: i8,
-    clay: i8,
-    clay_robots: i8,
-    obsidian: i8,
-    obsidian_robots: i8,
-    geodes: i8,
-    geodes_robots: i8,
+    ore: i32,
+    ore_robots: i32,
+    clay: i32,
+    clay_robots: i32,
+    obsidian: i32,
+    obsidian_robots: i32,
+    geodes: i32,
+    geodes_robots: i32,
 }
 
 impl State {
@@ -189,7 +192,7 @@ impl State {
     }
 }
 
-fn find(blueprint: &Blueprint, minutes: &i8, state: &State) -> i8 {
+fn find(blueprint: &Blueprint, minutes: &i32, state: &State) -> i32 {
     return if *minutes <= 0 {
         state.geodes
     } else {
@@ -206,35 +209,103 @@ fn find(blueprint: &Blueprint, minutes: &i8, state: &State) -> i8 {
     };
 }
 
-fn parse_blueprints(content: &str) -> Vec<Blueprint> {
-    content
-        .split("\n")
-        .map(|line| Blueprint::new(line))
-        .collect_vec()
+fn parse_blueprints(content: &str) -> Result<Vec<Blueprint>, ParseError> {
+    parse_lines(content, Blueprint::new)
 }
 
-fn part1(blueprints: &Vec<Blueprint>) -> i32 {
+fn part1(blueprints: &Vec<Blueprint>, minutes: i32) -> i32 {
     let mut res: i32 = 0;
     for i in 0..blueprints.len() {
-        let result = find(blueprints.get(i).unwrap(), &24, &State::default());
-        res += (i + 1) as i32 * result as i32;
+        let result = find(blueprints.get(i).unwrap(), &minutes, &State::default());
+        res += (i + 1) as i32 * result;
     }
     res
 }
 
+fn part2(blueprints: &Vec<Blueprint>, minutes: i32, count: usize) -> i32 {
+    blueprints.iter()
+        .take(count)
+        .map(|blueprint| find(blueprint, &minutes, &State::default()))
+        .product()
+}
+
+pub(crate) struct Day19 {
+    /// Minutes to collect geodes in part 1.
+    pub(crate) minutes: i32,
+    /// Minutes to collect geodes in part 2.
+    pub(crate) long_minutes: i32,
+    /// Blueprints left in part 2, counted from the first one.
+    pub(crate) blueprints: usize,
+}
 
-fn part2(blueprints: &Vec<Blueprint>) -> i32 {
-    let mut res: i32 = 1;
-    for i in 0..3 {
-        let result = find(blueprints.get(i).unwrap(), &32, &State::default());
-        res *= result as i32
+impl Default for Day19 {
+    fn default() -> Day19 {
+        Day19 { minutes: 24, long_minutes: 32, blueprints: 3 }
+    }
+}
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Vec<Blueprint>, ParseError> {
+        parse_blueprints(content)
+    }
+
+    fn part1(&self, blueprints: &Vec<Blueprint>) -> i32 {
+        part1(blueprints, self.minutes)
+    }
+
+    fn part2(&self, blueprints: &Vec<Blueprint>) -> i32 {
+        part2(blueprints, self.long_minutes, self.blueprints)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "minutes" => self.minutes = parameter(key, value)?,
+            "long_minutes" => self.long_minutes = parameter(key, value)?,
+            "blueprints" => self.blueprints = parameter(key, value)?,
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
     }
-    res
 }
 
-pub(crate) fn solve() {
-    let content = read_to_string("19.txt").unwrap();
-    let blueprints = parse_blueprints(&content);
-    println!("{}", part1(&blueprints));
-    println!("{}", part2(&blueprints));
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn part1_example() {
+        let blueprints = Day19::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day19::default().part1(&blueprints), 33);
+    }
+
+    #[test]
+    fn part2_with_fewer_blueprints() {
+        let day = Day19 { long_minutes: 24, ..Day19::default() };
+        let blueprints = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part2(&blueprints), 9 * 12);
+    }
+
+    #[test]
+    fn costs_beyond_a_byte() {
+        // Costs and stock above 127 used to overflow the i8 counters.
+        let day = Day19 { minutes: 10, ..Day19::default() };
+        let blueprints = day.parse("Blueprint 1: Each ore robot costs 200 ore. Each clay robot costs 150 ore. \
+            Each obsidian robot costs 3 ore and 140 clay. Each geode robot costs 2 ore and 250 obsidian.").unwrap();
+        assert_eq!(blueprints[0].geode_obsidian, 250);
+        assert_eq!(day.part1(&blueprints), 0);
+    }
+
+    #[test]
+    fn geodes_of_first_blueprint() {
+        let blueprints = Day19::default().parse(EXAMPLE).unwrap();
+        assert_eq!(find(&blueprints[0], &24, &State::default()), 9);
+    }
+}
@@ -0,0 +1,478 @@
+use itertools::Itertools;
+use crate::bench::Format;
+use crate::parse::{Expected, parse_lines, ParseError, split_once};
+use crate::solution::{Maybe, parameter, Solution, unknown_parameter};
+
+/// Outcome of a round for the player, in the order the score tables of a ruleset use.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Outcome {
+    Lose = 0,
+    Draw = 1,
+    Win = 2,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Outcome::Lose => "lose",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        }
+    }
+}
+
+/// `"A, B or C"` for the error of a symbol that is none of `symbols`.
+fn one_of(symbols: &[String]) -> Expected {
+    let listed = match symbols.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} or {}", rest.join(", "), last),
+        None => String::new()
+    };
+    Expected::OneOf(listed)
+}
+
+/// Moves of a game like Rock-Paper-Scissors, how the strategy guide writes them and how rounds are scored.
+/// Moves are referred to by their index in `moves`.
+#[derive(Clone, Debug)]
+pub(crate) struct Ruleset {
+    moves: Vec<String>,
+    /// `beats[a][b]` tells whether move `a` wins against move `b`.
+    beats: Vec<Vec<bool>>,
+    /// Symbols of the first column, one per move.
+    opponent_symbols: Vec<String>,
+    /// Symbols of the second column read as moves, one per move.
+    player_symbols: Vec<String>,
+    /// Symbols of the second column read as the outcome to reach, indexed by `Outcome`.
+    outcome_symbols: [String; 3],
+    /// Score for playing each move.
+    move_scores: Vec<i32>,
+    /// Score for each outcome, indexed by `Outcome`.
+    outcome_scores: [i32; 3],
+}
+
+impl Ruleset {
+    /// Tournament of an odd number of moves where every move beats the half of the others listed right before it,
+    /// wrapping around. Moves score their position counting from 1, the player writes them with the letters up to Z.
+    pub(crate) fn cyclic(moves: &[&str]) -> Result<Ruleset, String> {
+        let size = moves.len();
+        if size.is_multiple_of(2) || size > 25 {
+            return Err(format!("a cyclic tournament needs an odd number of moves up to 25, not {}", size));
+        }
+        let letters = |first: u8| (first..first + size as u8).map(|c| (c as char).to_string()).collect_vec();
+        Ok(Ruleset {
+            moves: moves.iter().map(|name| name.to_string()).collect(),
+            beats: (0..size)
+                .map(|a| (0..size).map(|b| (1..=size / 2).any(|distance| (b + distance) % size == a)).collect())
+                .collect(),
+            opponent_symbols: letters(b'A'),
+            player_symbols: letters(b'Z' + 1 - size as u8),
+            outcome_symbols: ["X", "Y", "Z"].map(String::from),
+            move_scores: (1..=size as i32).collect(),
+            outcome_scores: [0, 3, 6],
+        })
+    }
+
+    /// The game of the puzzle.
+    pub(crate) fn rock_paper_scissors() -> Ruleset {
+        Ruleset::cyclic(&["Rock", "Paper", "Scissors"]).unwrap()
+    }
+
+    /// Rock-Paper-Scissors-Lizard-Spock, written with A to E and V to Z.
+    pub(crate) fn rock_paper_scissors_lizard_spock() -> Ruleset {
+        Ruleset::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).unwrap()
+    }
+
+    fn outcome(&self, opponent: usize, me: usize) -> Outcome {
+        return if self.beats[me][opponent] {
+            Outcome::Win
+        } else if self.beats[opponent][me] {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        };
+    }
+
+    fn score(&self, opponent: usize, me: usize) -> i32 {
+        self.move_scores[me] + self.outcome_scores[self.outcome(opponent, me) as usize]
+    }
+
+    /// Move that scores the most against `opponent`.
+    fn best_move(&self, opponent: usize) -> usize {
+        (0..self.moves.len())
+            .max_by_key(|&me| self.score(opponent, me))
+            .unwrap()
+    }
+
+    /// Best scoring move that reaches `outcome` against `opponent`.
+    fn move_to_play(&self, opponent: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.moves.len())
+            .filter(|&me| self.outcome(opponent, me) == outcome)
+            .max_by_key(|&me| self.move_scores[me])
+    }
+
+    /// Symbols of a column, one per move or outcome, separated by commas.
+    fn symbols(key: &str, value: &str, count: usize) -> Result<Vec<String>, String> {
+        let symbols = value.split(",").map(|symbol| symbol.trim().to_string()).collect_vec();
+        return if symbols.len() == count && symbols.iter().all_unique() && symbols.iter().all(|symbol| !symbol.is_empty()) {
+            Ok(symbols)
+        } else {
+            Err(format!("invalid value '{}' for parameter '{}', expected {} different symbols separated by commas", value, key, count))
+        };
+    }
+
+    /// Scores separated by commas, one per move or outcome.
+    fn scores(key: &str, value: &str, count: usize) -> Result<Vec<i32>, String> {
+        let scores = value.split(",").map(|score| parameter(key, score)).collect::<Result<Vec<i32>, String>>()?;
+        return if scores.len() == count {
+            Ok(scores)
+        } else {
+            Err(format!("invalid value '{}' for parameter '{}', expected {} scores separated by commas", value, key, count))
+        };
+    }
+}
+
+impl Default for Ruleset {
+    fn default() -> Ruleset {
+        Ruleset::rock_paper_scissors()
+    }
+}
+
+/// Single line of the strategy guide, with the second column read both as a move and as an outcome to reach.
+/// A custom ruleset can give the second column symbols meaning only one of them.
+pub(crate) struct Round {
+    opponent: usize,
+    me: Option<usize>,
+    outcome: Option<Outcome>,
+}
+
+impl Round {
+    fn new(line: &str, ruleset: &Ruleset) -> Result<Round, ParseError> {
+        let (opponent, me) = split_once(line, line, " ")?;
+        let round = Round {
+            opponent: ruleset.opponent_symbols.iter().position(|symbol| symbol == opponent)
+                .ok_or_else(|| ParseError::new(line, opponent, one_of(&ruleset.opponent_symbols)))?,
+            me: ruleset.player_symbols.iter().position(|symbol| symbol == me),
+            outcome: ruleset.outcome_symbols.iter().position(|symbol| symbol == me).map(|outcome| Outcome::ALL[outcome]),
+        };
+        return if round.me.is_some() || round.outcome.is_some() {
+            Ok(round)
+        } else {
+            let symbols = ruleset.player_symbols.iter().chain(&ruleset.outcome_symbols).unique().cloned().collect_vec();
+            Err(ParseError::new(line, me, one_of(&symbols)))
+        };
+    }
+}
+
+/// Total score with the second column read as moves, `None` when a round's symbol is no move of the ruleset.
+fn part1(rounds: &[Round], ruleset: &Ruleset) -> Option<i32> {
+    rounds.iter()
+        .map(|round| round.me.map(|me| ruleset.score(round.opponent, me)))
+        .sum()
+}
+
+/// Total score with the second column read as outcomes, `None` when a round's symbol is no outcome
+/// of the ruleset or no move reaches it.
+fn part2(rounds: &[Round], ruleset: &Ruleset) -> Option<i32> {
+    rounds.iter()
+        .map(|round| round.outcome
+            .and_then(|outcome| ruleset.move_to_play(round.opponent, outcome))
+            .map(|me| ruleset.score(round.opponent, me)))
+        .sum()
+}
+
+/// Move played in a round with its outcome and score.
+#[derive(Clone, Copy)]
+struct Play {
+    me: usize,
+    outcome: Outcome,
+    score: i32,
+}
+
+impl Play {
+    fn new(ruleset: &Ruleset, opponent: usize, me: usize) -> Play {
+        Play { me, outcome: ruleset.outcome(opponent, me), score: ruleset.score(opponent, me) }
+    }
+}
+
+/// The round played with the second column read as a move, read as an outcome and with the best move,
+/// `None` for a reading the round cannot be played with.
+fn plays(round: &Round, ruleset: &Ruleset) -> [Option<Play>; 3] {
+    let opponent = round.opponent;
+    [
+        round.me.map(|me| Play::new(ruleset, opponent, me)),
+        round.outcome
+            .and_then(|outcome| ruleset.move_to_play(opponent, outcome))
+            .map(|me| Play::new(ruleset, opponent, me)),
+        Some(Play::new(ruleset, opponent, ruleset.best_move(opponent))),
+    ]
+}
+
+/// Scores and outcomes of the rounds played one way.
+#[derive(Default)]
+struct Tally {
+    score: i32,
+    /// Rounds that ended in each outcome, indexed by `Outcome`.
+    outcomes: [usize; 3],
+}
+
+impl Tally {
+    fn add(&mut self, ruleset: &Ruleset, play: Option<Play>) -> String {
+        match play {
+            Some(play) => {
+                self.score += play.score;
+                self.outcomes[play.outcome as usize] += 1;
+                format!("{} {} {}", ruleset.moves[play.me], play.outcome.name(), play.score)
+            }
+            None => "-".to_string()
+        }
+    }
+
+    fn summary(&self) -> String {
+        format!("{} ({} won, {} drawn, {} lost)", self.score,
+                self.outcomes[Outcome::Win as usize], self.outcomes[Outcome::Draw as usize], self.outcomes[Outcome::Lose as usize])
+    }
+}
+
+/// Every round played with the second column read as a move, read as an outcome and with the best move,
+/// followed by the totals, to see how good the strategy guide is under either reading.
+fn table(rounds: &[Round], ruleset: &Ruleset) -> String {
+    let (mut as_move, mut as_outcome, mut best) = (Tally::default(), Tally::default(), Tally::default());
+    let mut lines = vec![format!("{:>5}  {:<10}  {:<20}  {:<20}  {}", "Round", "Opponent", "As move", "As outcome", "Best")];
+    for (index, round) in rounds.iter().enumerate() {
+        let [played, reached, optimal] = plays(round, ruleset);
+        let (played, reached, optimal) = (as_move.add(ruleset, played), as_outcome.add(ruleset, reached), best.add(ruleset, optimal));
+        lines.push(format!("{:>5}  {:<10}  {:<20}  {:<20}  {}", index + 1, ruleset.moves[round.opponent], played, reached, optimal));
+    }
+    let share = |tally: &Tally| match best.score {
+        0 => "-".to_string(),
+        _ => format!("{:.1}%", tally.score as f64 * 100.0 / best.score as f64)
+    };
+    lines.push(String::new());
+    lines.push(format!("As move:    {}, {} of the best", as_move.summary(), share(&as_move)));
+    lines.push(format!("As outcome: {}, {} of the best", as_outcome.summary(), share(&as_outcome)));
+    lines.push(format!("Best:       {}", best.summary()));
+    lines.join("\n")
+}
+
+const READINGS: [&str; 3] = ["as_move", "as_outcome", "best"];
+
+fn json(rounds: &[Round], ruleset: &Ruleset) -> String {
+    let entries = rounds.iter()
+        .enumerate()
+        .map(|(index, round)| {
+            let plays = plays(round, ruleset).iter()
+                .zip(READINGS)
+                .map(|(play, reading)| match play {
+                    Some(play) => format!("\"{0}\": \"{1}\", \"{0}_outcome\": \"{2}\", \"{0}_score\": {3}",
+                                          reading, ruleset.moves[play.me], play.outcome.name(), play.score),
+                    None => format!("\"{0}\": null, \"{0}_outcome\": null, \"{0}_score\": null", reading)
+                })
+                .join(", ");
+            format!("  {{\"round\": {}, \"opponent\": \"{}\", {}}}", index + 1, ruleset.moves[round.opponent], plays)
+        })
+        .join(",\n");
+    format!("[\n{}\n]", entries)
+}
+
+fn csv(rounds: &[Round], ruleset: &Ruleset) -> String {
+    let columns = READINGS.iter().map(|reading| format!("{0},{0}_outcome,{0}_score", reading)).join(",");
+    let mut lines = vec![format!("round,opponent,{}", columns)];
+    for (index, round) in rounds.iter().enumerate() {
+        let plays = plays(round, ruleset).iter()
+            .map(|play| match play {
+                Some(play) => format!("{},{},{}", ruleset.moves[play.me], play.outcome.name(), play.score),
+                None => ",,".to_string()
+            })
+            .join(",");
+        lines.push(format!("{},{},{}", index + 1, ruleset.moves[round.opponent], plays));
+    }
+    lines.join("\n")
+}
+
+/// The strategy guide played every way, laid out like the other reports.
+fn report(rounds: &[Round], ruleset: &Ruleset, format: Format) -> String {
+    match format {
+        Format::Table => table(rounds, ruleset),
+        Format::Json => json(rounds, ruleset),
+        Format::Csv => csv(rounds, ruleset),
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct Day2 {
+    /// Game the strategy guide is written for.
+    pub(crate) ruleset: Ruleset,
+}
+
+impl Solution for Day2 {
+    type Input = Vec<Round>;
+    type Output1 = Maybe<i32>;
+    type Output2 = Maybe<i32>;
+
+    fn parse(&self, contents: &str) -> Result<Vec<Round>, ParseError> {
+        parse_lines(contents, |line| Round::new(line, &self.ruleset))
+    }
+
+    fn part1(&self, rounds: &Vec<Round>) -> Maybe<i32> {
+        Maybe(part1(rounds, &self.ruleset))
+    }
+
+    fn part2(&self, rounds: &Vec<Round>) -> Maybe<i32> {
+        Maybe(part2(rounds, &self.ruleset))
+    }
+
+    fn report(&self, rounds: &Vec<Round>, _colour: bool, format: Format) -> Option<String> {
+        Some(report(rounds, &self.ruleset, format))
+    }
+
+    /// `ruleset` (rps or rpsls) and `moves` (size of a cyclic tournament) start from a new ruleset,
+    /// the other parameters change the symbols or scores of the current one.
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        let ruleset = &mut self.ruleset;
+        let size = ruleset.moves.len();
+        match key {
+            "ruleset" => *ruleset = match value {
+                "rps" => Ruleset::rock_paper_scissors(),
+                "rpsls" => Ruleset::rock_paper_scissors_lizard_spock(),
+                other => return Err(format!("invalid ruleset '{}', expected rps or rpsls", other))
+            },
+            "moves" => {
+                let size: usize = parameter(key, value)?;
+                let names = (1..=size).map(|n| format!("Move {}", n)).collect_vec();
+                *ruleset = Ruleset::cyclic(&names.iter().map(|name| name.as_str()).collect_vec())?;
+            }
+            "opponent" => ruleset.opponent_symbols = Ruleset::symbols(key, value, size)?,
+            "player" => ruleset.player_symbols = Ruleset::symbols(key, value, size)?,
+            "outcomes" => ruleset.outcome_symbols = Ruleset::symbols(key, value, 3)?.try_into().unwrap(),
+            "move_scores" => ruleset.move_scores = Ruleset::scores(key, value, size)?,
+            "outcome_scores" => ruleset.outcome_scores = Ruleset::scores(key, value, 3)?.try_into().unwrap(),
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Puzzle;
+    use super::*;
+
+    const EXAMPLE: &str = "\
+A Y
+B X
+C Z";
+
+    #[test]
+    fn part1_example() {
+        let rounds = Day2::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::default().part1(&rounds), Maybe(Some(15)));
+    }
+
+    #[test]
+    fn part2_example() {
+        let rounds = Day2::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day2::default().part2(&rounds), Maybe(Some(12)));
+    }
+
+    #[test]
+    fn analysis_of_example() {
+        let rounds = Day2::default().parse(EXAMPLE).unwrap();
+        assert_eq!(report(&rounds, &Ruleset::default(), Format::Table), "\
+Round  Opponent    As move               As outcome            Best
+    1  Rock        Paper win 8           Rock draw 4           Paper win 8
+    2  Paper       Rock lose 1           Rock lose 1           Scissors win 9
+    3  Scissors    Scissors draw 6       Rock win 7            Rock win 7
+
+As move:    15 (1 won, 1 drawn, 1 lost), 62.5% of the best
+As outcome: 12 (1 won, 1 drawn, 1 lost), 50.0% of the best
+Best:       24 (3 won, 0 drawn, 0 lost)");
+    }
+
+    #[test]
+    fn analysis_without_points() {
+        let mut day = Day2::default();
+        for (key, value) in [("move_scores", "0,0,0"), ("outcome_scores", "0,0,0")] {
+            Solution::configure(&mut day, key, value).unwrap();
+        }
+        let report = report(&day.parse(EXAMPLE).unwrap(), &day.ruleset, Format::Table);
+        assert!(report.contains("As move:    0 (1 won, 1 drawn, 1 lost), - of the best"));
+    }
+
+    #[test]
+    fn analysis_as_json_and_csv() {
+        let mut day = Day2::default();
+        Solution::configure(&mut day, "outcomes", "X,Y,W").unwrap();
+        let rounds = day.parse("A Y\nB Z").unwrap();
+        assert_eq!(report(&rounds, &day.ruleset, Format::Json), "[
+  {\"round\": 1, \"opponent\": \"Rock\", \"as_move\": \"Paper\", \"as_move_outcome\": \"win\", \"as_move_score\": 8, \
+\"as_outcome\": \"Rock\", \"as_outcome_outcome\": \"draw\", \"as_outcome_score\": 4, \
+\"best\": \"Paper\", \"best_outcome\": \"win\", \"best_score\": 8},
+  {\"round\": 2, \"opponent\": \"Paper\", \"as_move\": \"Scissors\", \"as_move_outcome\": \"win\", \"as_move_score\": 9, \
+\"as_outcome\": null, \"as_outcome_outcome\": null, \"as_outcome_score\": null, \
+\"best\": \"Scissors\", \"best_outcome\": \"win\", \"best_score\": 9}
+]");
+        assert_eq!(report(&rounds, &day.ruleset, Format::Csv), "\
+round,opponent,as_move,as_move_outcome,as_move_score,as_outcome,as_outcome_outcome,as_outcome_score,best,best_outcome,best_score
+1,Rock,Paper,win,8,Rock,draw,4,Paper,win,8
+2,Paper,Scissors,win,9,,,,Scissors,win,9");
+    }
+
+    #[test]
+    fn report_takes_the_requested_format() {
+        let report = Puzzle::report(&Day2::default(), EXAMPLE, false, Format::Csv).unwrap().unwrap();
+        assert_eq!(report.lines().count(), 4);
+        assert!(report.starts_with("round,opponent,"));
+    }
+
+    #[test]
+    fn winning_and_losing_moves() {
+        for ruleset in [Ruleset::rock_paper_scissors(), Ruleset::rock_paper_scissors_lizard_spock()] {
+            for choice in 0..ruleset.moves.len() {
+                for outcome in Outcome::ALL {
+                    let me = ruleset.move_to_play(choice, outcome).unwrap();
+                    assert_eq!(ruleset.outcome(choice, me), outcome);
+                }
+                let wins = (0..ruleset.moves.len()).filter(|&other| ruleset.beats[choice][other]).count();
+                assert_eq!(wins, ruleset.moves.len() / 2);
+            }
+        }
+    }
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let ruleset = Ruleset::rock_paper_scissors_lizard_spock();
+        let index = |name: &str| ruleset.moves.iter().position(|m| m == name).unwrap();
+        assert_eq!(ruleset.outcome(index("Scissors"), index("Spock")), Outcome::Win);
+        assert_eq!(ruleset.outcome(index("Lizard"), index("Spock")), Outcome::Lose);
+        assert_eq!(ruleset.outcome(index("Paper"), index("Lizard")), Outcome::Win);
+        assert_eq!(ruleset.outcome(index("Rock"), index("Lizard")), Outcome::Lose);
+
+        let mut day = Day2::default();
+        Solution::configure(&mut day, "ruleset", "rpsls").unwrap();
+        // Rock against Spock, then Scissors against Rock.
+        let rounds = day.parse("A W\nE V").unwrap();
+        assert_eq!(day.part1(&rounds), Maybe(Some((2 + 6) + (1 + 6))));
+        assert_eq!(day.part2(&rounds).to_string(), "-");
+        assert!(day.parse("A Z").is_ok());
+        assert!(day.parse("F X").is_err());
+    }
+
+    #[test]
+    fn custom_symbols_and_scores() {
+        let mut day = Day2::default();
+        for (key, value) in [("opponent", "R,P,S"), ("player", "r,p,s"), ("outcomes", "l,d,w"), ("outcome_scores", "-1,0,1")] {
+            Solution::configure(&mut day, key, value).unwrap();
+        }
+        // Paper beats Rock for 2 + 1, Scissors against Scissors is a draw worth nothing on top of its 3.
+        assert_eq!(day.part1(&day.parse("R p\nS s").unwrap()), Maybe(Some((2 + 1) + 3)));
+        assert_eq!(day.part2(&day.parse("R w\nS d").unwrap()), Maybe(Some((2 + 1) + 3)));
+        assert_eq!(day.part1(&day.parse("R w").unwrap()).to_string(), "-");
+        assert_eq!(day.parse("R x").err().unwrap().expected, Expected::OneOf("r, p, s, l, d or w".to_string()));
+        assert!(Solution::configure(&mut day, "player", "a,b").is_err());
+        assert!(Solution::configure(&mut day, "moves", "4").is_err());
+        Solution::configure(&mut day, "moves", "7").unwrap();
+        assert_eq!(day.ruleset.player_symbols.join(""), "TUVWXYZ");
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::VecDeque;
-use std::fs::read_to_string;
 use itertools::Itertools;
+use crate::parse::{number, parse_lines, ParseError};
+use crate::solution::Solution;
 
 fn encrypt(data: &Vec<i64>, rounds: i8) -> Vec<i64> {
     let with_position = (0..data.len())
@@ -44,12 +45,48 @@ fn part2(data: &Vec<i64>) -> i64 {
     get_result(&encrypt(&multiplied_data, 10))
 }
 
-pub(crate) fn solve() {
-    let content = read_to_string("20.txt").unwrap();
-    let numbers = content
-        .split("\n")
-        .map(|line| line.parse().unwrap())
-        .collect_vec();
-    println!("{}", part1(&numbers));
-    println!("{}", part2(&numbers));
-}
\ No newline at end of file
+pub(crate) struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, content: &str) -> Result<Vec<i64>, ParseError> {
+        parse_lines(content, |line| number(line, line))
+    }
+
+    fn part1(&self, numbers: &Vec<i64>) -> i64 {
+        part1(numbers)
+    }
+
+    fn part2(&self, numbers: &Vec<i64>) -> i64 {
+        part2(numbers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1
+2
+-3
+3
+-2
+0
+4";
+
+    #[test]
+    fn part1_example() {
+        let numbers = Day20.parse(EXAMPLE).unwrap();
+        assert_eq!(Day20.part1(&numbers), 3);
+    }
+
+    #[test]
+    fn part2_example() {
+        let numbers = Day20.parse(EXAMPLE).unwrap();
+        assert_eq!(Day20.part2(&numbers), 1623178306);
+    }
+}
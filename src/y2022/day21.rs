@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use crate::parse::{Expected, lines, number, ParseError, split_once};
+use crate::solution::Solution;
+
+pub(crate) enum Job {
+    Number(i64),
+    Operation(String, char, String),
+}
+
+impl Job {
+    fn new(line: &str, job: &str) -> Result<Job, ParseError> {
+        let parts = job.split(" ").collect_vec();
+        match parts[..] {
+            [value] => Ok(Job::Number(number(line, value)?)),
+            [left, op, right] if matches!(op, "+" | "-" | "*" | "/") =>
+                Ok(Job::Operation(left.to_string(), op.chars().next().unwrap(), right.to_string())),
+            [_, op, _] => Err(ParseError::new(line, op, Expected::OneOf("+, -, * or /".to_string()))),
+            _ => Err(ParseError::new(line, job, Expected::Format("<number> or <monkey> <op> <monkey>".to_string())))
+        }
+    }
+}
+
+fn parse_monkey(line: &str) -> Result<(String, Job), ParseError> {
+    let (name, job) = split_once(line, line, ": ")?;
+    Ok((name.to_string(), Job::new(line, job)?))
+}
+
+fn parse_monkeys(content: &str) -> Result<HashMap<String, Job>, ParseError> {
+    let mut monkeys = HashMap::new();
+    for (index, line) in lines(content).enumerate() {
+        let (name, job) = parse_monkey(line).map_err(|e| e.shifted(index))?;
+        monkeys.insert(name, job);
+    }
+    for (index, line) in lines(content).enumerate() {
+        let (_, job) = line.split_once(": ").unwrap();
+        for name in job.split(" ").step_by(2).filter(|name| name.parse::<i64>().is_err()) {
+            if !monkeys.contains_key(name) {
+                let expected = Expected::Format("the name of a monkey from the list".to_string());
+                return Err(ParseError::new(line, name, expected).shifted(index));
+            }
+        }
+    }
+    if !monkeys.contains_key("root") {
+        let last = lines(content).last().unwrap();
+        return Err(ParseError::new(last, "", Expected::Format("a monkey named root".to_string()))
+            .shifted(lines(content).count() - 1));
+    }
+    Ok(monkeys)
+}
+
+fn compute(left: i64, op: char, right: i64) -> i64 {
+    match op {
+        '+' => left + right,
+        '-' => left - right,
+        '*' => left * right,
+        _ => left / right
+    }
+}
+
+fn evaluate(monkeys: &HashMap<String, Job>, name: &str) -> i64 {
+    match monkeys.get(name).unwrap() {
+        Job::Number(value) => *value,
+        Job::Operation(left, op, right) => compute(evaluate(monkeys, left), *op, evaluate(monkeys, right))
+    }
+}
+
+fn depends_on_human(monkeys: &HashMap<String, Job>, name: &str) -> bool {
+    return if name == "humn" {
+        true
+    } else {
+        match monkeys.get(name).unwrap() {
+            Job::Number(_) => false,
+            Job::Operation(left, _, right) => depends_on_human(monkeys, left) | depends_on_human(monkeys, right)
+        }
+    };
+}
+
+/// Walks down from `name` towards `humn`, inverting every operation on the way so that `name` yells `target`.
+fn solve(monkeys: &HashMap<String, Job>, name: &str, target: i64) -> i64 {
+    if name == "humn" {
+        return target;
+    }
+    let (left, op, right) = match monkeys.get(name).unwrap() {
+        Job::Operation(left, op, right) => (left, *op, right),
+        Job::Number(_) => panic!("{} does not depend on humn", name)
+    };
+    return if depends_on_human(monkeys, left) {
+        let other = evaluate(monkeys, right);
+        let target = match op {
+            '+' => target - other,
+            '-' => target + other,
+            '*' => target / other,
+            _ => target * other
+        };
+        solve(monkeys, left, target)
+    } else {
+        let other = evaluate(monkeys, left);
+        let target = match op {
+            '+' => target - other,
+            '-' => other - target,
+            '*' => target / other,
+            _ => other / target
+        };
+        solve(monkeys, right, target)
+    };
+}
+
+fn part1(monkeys: &HashMap<String, Job>) -> i64 {
+    evaluate(monkeys, "root")
+}
+
+fn part2(monkeys: &HashMap<String, Job>) -> i64 {
+    let (left, right) = match monkeys.get("root").unwrap() {
+        Job::Operation(left, _, right) => (left, right),
+        Job::Number(_) => panic!("root has to compare two monkeys")
+    };
+    return if depends_on_human(monkeys, left) {
+        solve(monkeys, left, evaluate(monkeys, right))
+    } else {
+        solve(monkeys, right, evaluate(monkeys, left))
+    };
+}
+
+pub(crate) struct Day21;
+
+impl Solution for Day21 {
+    type Input = HashMap<String, Job>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, content: &str) -> Result<HashMap<String, Job>, ParseError> {
+        parse_monkeys(content)
+    }
+
+    fn part1(&self, monkeys: &HashMap<String, Job>) -> i64 {
+        part1(monkeys)
+    }
+
+    fn part2(&self, monkeys: &HashMap<String, Job>) -> i64 {
+        part2(monkeys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+
+    #[test]
+    fn part1_example() {
+        let monkeys = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(Day21.part1(&monkeys), 152);
+    }
+
+    #[test]
+    fn part2_example() {
+        let monkeys = Day21.parse(EXAMPLE).unwrap();
+        assert_eq!(Day21.part2(&monkeys), 301);
+    }
+
+    #[test]
+    fn human_on_the_right_side() {
+        let monkeys = Day21.parse("root: aaaa + bbbb\naaaa: 20\nbbbb: cccc / humn\ncccc: 100\nhumn: 1").unwrap();
+        assert_eq!(Day21.part2(&monkeys), 5);
+    }
+
+    #[test]
+    fn unknown_monkey_is_reported() {
+        let error = Day21.parse("root: aaaa + bbbb\naaaa: 2").err().unwrap();
+        assert_eq!((error.line, error.column), (1, 14));
+    }
+}
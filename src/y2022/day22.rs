@@ -0,0 +1,373 @@
+use itertools::Itertools;
+use regex::Regex;
+use crate::geometry::{Direction, Point, Point3};
+use crate::grid::Grid;
+use crate::parse::{Expected, lines, number, ParseError};
+use crate::solution::{Maybe, parameter, Solution, unknown_parameter};
+
+#[derive(Clone, Copy)]
+pub(crate) enum Move {
+    Step(i32),
+    Left,
+    Right,
+}
+
+/// Facing value used by the password, counted clockwise from east.
+fn facing(direction: Direction) -> i64 {
+    match direction {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct Board {
+    board: Grid<char>,
+    direction: Direction,
+    position: Point,
+}
+
+impl Board {
+    fn new(data: &str) -> Result<Board, ParseError> {
+        let expected = Expected::OneOf("' ', '.' or '#'".to_string());
+        let board = Grid::parse(data, |c| matches!(c, ' ' | '.' | '#'), expected)?;
+        let first = lines(data).next().unwrap_or("");
+        let start = board.row(0).iter().position(|&c| c == '.')
+            .ok_or_else(|| ParseError::new(first, first, Expected::Format("an open tile in the first row".to_string())))?;
+        Ok(Board {
+            board,
+            direction: Direction::East,
+            position: Point::new(start as i32, 0),
+        })
+    }
+
+    fn reset(&mut self) {
+        self.position = Point::new(self.board.row(0).iter().position(|&c| c == '.').unwrap() as i32, 0);
+        self.direction = Direction::East;
+    }
+
+    fn score(&self) -> i64 {
+        (self.position.y + 1) as i64 * 1000 + 4 * (self.position.x + 1) as i64 + facing(self.direction)
+    }
+
+    fn tile(&self, position: Point) -> Option<char> {
+        self.board.get(position)
+            .copied()
+            .filter(|&c| c != ' ')
+    }
+
+    fn make_move(&mut self, m: Move, wrap: &impl Fn(&Board) -> (Point, Direction)) {
+        match m {
+            Move::Step(steps) => self.move_forward(steps, wrap),
+            Move::Left => self.direction = self.direction.turn_left(),
+            Move::Right => self.direction = self.direction.turn_right()
+        }
+    }
+
+    fn move_forward(&mut self, steps: i32, wrap: &impl Fn(&Board) -> (Point, Direction)) {
+        for _ in 0..steps {
+            let (new_position, new_dir) = self.single_step(wrap);
+            if self.tile(new_position).unwrap() == '#' {
+                break;
+            } else {
+                self.position = new_position;
+                self.direction = new_dir;
+            }
+        }
+    }
+
+    fn single_step(&self, wrap: &impl Fn(&Board) -> (Point, Direction)) -> (Point, Direction) {
+        let new_position = self.position + self.direction.vector();
+        return match self.tile(new_position) {
+            None => wrap(self),
+            Some(_) => (new_position, self.direction)
+        };
+    }
+}
+
+fn parse_board(board: &str) -> Result<Board, ParseError> {
+    Board::new(board)
+}
+
+fn parse_move(moves: &str, m: &str) -> Result<Move, ParseError> {
+    match m {
+        "L" => Ok(Move::Left),
+        "R" => Ok(Move::Right),
+        steps if steps.starts_with(|c: char| c.is_ascii_digit()) => Ok(Move::Step(number(moves, steps)?)),
+        other => Err(ParseError::new(moves, other, Expected::OneOf("L, R or a number of steps".to_string())))
+    }
+}
+
+fn parse_moves(moves: &str) -> Result<Vec<Move>, ParseError> {
+    let pattern = Regex::new(r"[LR]|\d+|.").unwrap();
+    pattern.find_iter(moves)
+        .map(|m| parse_move(moves, m.as_str()))
+        .collect()
+}
+
+fn parse_inputs(content: &str) -> Result<(Board, Vec<Move>), ParseError> {
+    let (board, moves) = content.split_once("\n\n")
+        .ok_or_else(|| ParseError::new("", "", Expected::Format("an empty line between the map and the path".to_string()))
+            .shifted(lines(content).count()))?;
+    let moves_offset = lines(board).count() + 1;
+    let board = parse_board(board)?;
+    Ok((board, parse_moves(moves).map_err(|e| e.shifted(moves_offset))?))
+}
+
+fn make_moves(board: &mut Board, moves: &[Move], wrap: &impl Fn(&Board) -> (Point, Direction)) -> i64 {
+    board.reset();
+    for m in moves {
+        board.make_move(*m, wrap);
+    }
+    board.score()
+}
+
+fn wrap_flat(board: &Board) -> (Point, Direction) {
+    let back = board.direction.reverse().vector();
+    let mut position = board.position;
+    while board.tile(position + back).is_some() {
+        position += back;
+    }
+    (position, board.direction)
+}
+
+/// Orientation of a face of the folded cube, `right` and `down` point where `x` and `y` grow on the map.
+#[derive(Clone, Copy)]
+struct Face {
+    corner: Point,
+    normal: Point3,
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    fn vector(&self, direction: Direction) -> Point3 {
+        match direction {
+            Direction::East => self.right,
+            Direction::South => self.down,
+            Direction::West => -self.right,
+            Direction::North => -self.down
+        }
+    }
+
+    /// The face the net folds to across the edge in `direction`.
+    fn neighbour(&self, direction: Direction, size: i32) -> Face {
+        let corner = self.corner + direction.vector() * size;
+        let (normal, inwards) = (self.vector(direction), -self.normal);
+        match direction {
+            Direction::East => Face { corner, normal, right: inwards, down: self.down },
+            Direction::West => Face { corner, normal, right: -inwards, down: self.down },
+            Direction::South => Face { corner, normal, right: self.right, down: inwards },
+            Direction::North => Face { corner, normal, right: self.right, down: -inwards }
+        }
+    }
+}
+
+/// The map folded into a cube with faces of `size` by `size` tiles.
+pub(crate) struct Cube {
+    size: i32,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    /// Folds the net around the face of the start tile, `None` unless every tile ends up on one of six distinct faces.
+    fn fold(board: &Board, size: i32) -> Option<Cube> {
+        if size <= 0 {
+            return None;
+        }
+        let first = Face {
+            corner: Point::new(board.position.x / size * size, 0),
+            normal: Point3::new(0, 0, -1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        };
+        let mut faces = vec![first];
+        let mut index = 0;
+        while index < faces.len() {
+            for direction in Direction::ALL {
+                let next = faces[index].neighbour(direction, size);
+                if board.tile(next.corner).is_some() && faces.iter().all(|face| face.corner != next.corner) {
+                    faces.push(next);
+                }
+            }
+            index += 1;
+        }
+        let cube = Cube { size, faces };
+        let tiles = board.board.iter().filter(|(_, c)| **c != ' ').collect_vec();
+        let folded = cube.faces.len() == 6
+            && cube.faces.iter().map(|face| face.normal).all_unique()
+            && tiles.len() as i32 == 6 * size * size
+            && tiles.iter().all(|(position, _)| cube.face(*position).is_some());
+        folded.then_some(cube)
+    }
+
+    fn face(&self, position: Point) -> Option<&Face> {
+        let corner = Point::new(position.x / self.size * self.size, position.y / self.size * self.size);
+        self.faces.iter().find(|face| face.corner == corner)
+    }
+
+    /// Walks over the edge of the face the board is on, working with doubled coordinates
+    /// of a cube centred at the origin so the centre of every tile is a point of the grid.
+    /// `fold` made sure every tile lies on a face and every side of the cube has one.
+    fn wrap(&self, board: &Board) -> (Point, Direction) {
+        let size = self.size;
+        let from = self.face(board.position).unwrap();
+        let offset = board.position - from.corner;
+        let centre = from.normal * size + from.right * (2 * offset.x + 1 - size) + from.down * (2 * offset.y + 1 - size);
+        let travel = from.vector(board.direction);
+        let to = self.faces.iter().find(|face| face.normal == travel).unwrap();
+        let entered = centre + travel - from.normal;
+        let position = to.corner + Point::new((entered.dot(&to.right) + size - 1) / 2, (entered.dot(&to.down) + size - 1) / 2);
+        let direction = Direction::ALL.into_iter().find(|d| to.vector(*d) == -from.normal).unwrap();
+        (position, direction)
+    }
+}
+
+fn part1(board: &mut Board, moves: &[Move]) -> i64 {
+    make_moves(board, moves, &wrap_flat)
+}
+
+/// Password of the walk on the cube, `None` when the map does not fold into a cube with faces of `size` tiles.
+fn part2(board: &mut Board, moves: &[Move], size: i32) -> Option<i64> {
+    let cube = Cube::fold(board, size)?;
+    Some(make_moves(board, moves, &|board: &Board| cube.wrap(board)))
+}
+
+pub(crate) struct Day22 {
+    /// Number of tiles along the edge of a face of the cube.
+    pub(crate) size: i32,
+}
+
+impl Default for Day22 {
+    fn default() -> Day22 {
+        Day22 { size: 50 }
+    }
+}
+
+impl Solution for Day22 {
+    type Input = (Board, Vec<Move>);
+    type Output1 = i64;
+    type Output2 = Maybe<i64>;
+
+    fn parse(&self, content: &str) -> Result<Self::Input, ParseError> {
+        parse_inputs(content)
+    }
+
+    fn part1(&self, (board, moves): &Self::Input) -> i64 {
+        part1(&mut board.clone(), moves)
+    }
+
+    fn part2(&self, (board, moves): &Self::Input) -> Maybe<i64> {
+        Maybe(part2(&mut board.clone(), moves, self.size))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "size" => self.size = parameter(key, value)?,
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "        ...#\n",
+        "        .#..\n",
+        "        #...\n",
+        "        ....\n",
+        "...#.......#\n",
+        "........#...\n",
+        "..#....#....\n",
+        "..........#.\n",
+        "        ...#....\n",
+        "        .....#..\n",
+        "        .#......\n",
+        "        ......#.\n",
+        "\n",
+        "10R5L5R10L4R5L5"
+    );
+
+    const DAY: Day22 = Day22 { size: 4 };
+
+    #[test]
+    fn part1_example() {
+        let input = DAY.parse(EXAMPLE).unwrap();
+        assert_eq!(DAY.part1(&input), 6032);
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = DAY.parse(EXAMPLE).unwrap();
+        assert_eq!(DAY.part2(&input), Maybe(Some(5031)));
+    }
+
+    #[test]
+    fn part2_example_final_position() {
+        // The puzzle text ends the walk on the cube at row 5, column 7, facing up.
+        let (mut board, moves) = DAY.parse(EXAMPLE).unwrap();
+        part2(&mut board, &moves, 4);
+        assert_eq!((board.position, board.direction), (Point::new(6, 4), Direction::North));
+    }
+
+    #[test]
+    fn cube_faces() {
+        let (board, _) = DAY.parse(EXAMPLE).unwrap();
+        let cube = Cube::fold(&board, 4).unwrap();
+        assert_eq!(cube.faces.len(), 6);
+        assert!(cube.faces.iter().map(|face| face.normal).all_unique());
+    }
+
+    #[test]
+    fn map_that_does_not_fold_has_no_part2() {
+        let day = Day22::default();
+        let input = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part1(&input), 6032);
+        assert_eq!(day.part2(&input).to_string(), "-");
+        let strip = "....\n".repeat(4).replace("....", "........................");
+        let input = DAY.parse(&format!("{}\n1", strip)).unwrap();
+        assert_eq!(DAY.part2(&input), Maybe(None));
+        assert_eq!(Day22 { size: 0 }.part2(&DAY.parse(EXAMPLE).unwrap()), Maybe(None));
+    }
+
+    #[test]
+    fn closed_first_row_is_reported() {
+        let error = Board::new("  #\n  .").err().unwrap();
+        assert_eq!((error.line, error.found.as_str()), (1, "  #"));
+    }
+
+    #[test]
+    fn start_is_leftmost_open_tile() {
+        let (board, _) = DAY.parse(EXAMPLE).unwrap();
+        assert_eq!(board.position, Point::new(8, 0));
+    }
+
+    #[test]
+    fn start_skips_walls_and_padding() {
+        let board = Board::new("  #..\n  ...").unwrap();
+        assert_eq!(board.position, Point::new(3, 0));
+    }
+
+    #[test]
+    fn facing_values() {
+        assert_eq!(Direction::ALL.map(facing), [3, 0, 1, 2]);
+    }
+
+    #[test]
+    fn moves_are_parsed() {
+        let moves = parse_moves("10R5L5").unwrap();
+        assert_eq!(moves.len(), 5);
+        assert!(matches!(moves[0], Move::Step(10)));
+        assert!(matches!(moves[1], Move::Right));
+    }
+
+    #[test]
+    fn invalid_move_is_reported() {
+        assert!(parse_moves("10X5").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use crate::geometry::{Direction, Point};
+use crate::grid::{Grid, SparseGrid};
+use crate::parse::{Expected, ParseError};
+use crate::solution::Solution;
+
+/// Order in which the elves consider the directions, the first one moves to the back every round.
+const ORDER: [Direction; 4] = [Direction::North, Direction::South, Direction::West, Direction::East];
+
+/// The three neighbours that have to be free to move in `direction`.
+fn check_positions(direction: Direction) -> [Point; 3] {
+    let vector = direction.vector();
+    [vector, vector + direction.turn_left().vector(), vector + direction.turn_right().vector()]
+}
+
+#[derive(Clone)]
+pub(crate) struct Board {
+    positions: SparseGrid<char>,
+    round: usize,
+}
+
+impl Board {
+    fn new(content: &str) -> Result<Board, ParseError> {
+        let grid = Grid::parse(content, |c| c == '.' || c == '#', Expected::OneOf("'.' or '#'".to_string()))?;
+        Ok(Board {
+            positions: grid.iter().filter(|(_, c)| **c == '#').map(|(position, c)| (position, *c)).collect(),
+            round: 0,
+        })
+    }
+
+    fn is_fixed_position(&self, position: &Point) -> bool {
+        self.positions.neighbours8(*position).next().is_none()
+    }
+
+    fn score(&self) -> i32 {
+        let (top_left, bottom_right) = self.positions.bounds().unwrap();
+        let size = bottom_right - top_left + Point::new(1, 1);
+        size.x * size.y - self.positions.len() as i32
+    }
+
+    fn nothing_moves(&self) -> bool {
+        self.positions
+            .positions()
+            .all(|pos| self.is_fixed_position(&pos))
+    }
+
+    fn can_elf_move_in_direction(&self, position: &Point, direction: Direction) -> bool {
+        check_positions(direction).iter().all(|check| !self.positions.contains(*position + *check))
+    }
+
+    fn move_one_elf(&self, position: Point) -> Point {
+        if self.is_fixed_position(&position) {
+            return position;
+        }
+        (0..4)
+            .map(|offset| ORDER[(self.round + offset) % 4])
+            .find(|direction| self.can_elf_move_in_direction(&position, *direction))
+            .map(|direction| position + direction.vector())
+            .unwrap_or(position)
+    }
+
+    fn play_round(&mut self) -> Board {
+        let mut taken: HashMap<Point, i32> = HashMap::new();
+        let mut moves: HashMap<Point, Point> = HashMap::new();
+        for position in self.positions.positions() {
+            let target = self.move_one_elf(position);
+            moves.insert(position, target);
+            *taken.entry(target).or_insert(0) += 1;
+        }
+        // Elves only move to free cells, so moving them one by one never overwrites another elf.
+        let mut positions = self.positions.clone();
+        for (pos, elf) in self.positions.iter() {
+            let target_pos = moves[&pos];
+            if target_pos != pos && taken[&target_pos] == 1 {
+                positions.remove(pos);
+                positions.insert(target_pos, *elf);
+            }
+        }
+        Board {
+            positions,
+            round: self.round + 1,
+        }
+    }
+}
+
+impl ToString for Board {
+    fn to_string(&self) -> String {
+        let (top_left, bottom_right) = self.positions.bounds().unwrap();
+        self.positions.render((top_left - Point::new(1, 1), bottom_right + Point::new(1, 1)), '.', |c| *c)
+    }
+}
+
+fn part1(initial_board: Board) -> i32 {
+    let mut board = initial_board;
+    for _ in 0..10 {
+        board = board.play_round()
+    }
+    board.score()
+}
+
+fn part2(initial_board: Board) -> i32 {
+    let mut round = 1;
+    let mut board = initial_board;
+    loop {
+        if board.nothing_moves() {
+            return round;
+        } else {
+            round += 1;
+            board = board.play_round();
+        }
+    }
+}
+
+pub(crate) struct Day23;
+
+impl Solution for Day23 {
+    type Input = Board;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Board, ParseError> {
+        Board::new(content)
+    }
+
+    fn part1(&self, board: &Board) -> i32 {
+        part1(board.clone())
+    }
+
+    fn part2(&self, board: &Board) -> i32 {
+        part2(board.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..";
+
+    #[test]
+    fn part1_example() {
+        let board = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part1(&board), 110);
+    }
+
+    #[test]
+    fn part2_example() {
+        let board = Day23.parse(EXAMPLE).unwrap();
+        assert_eq!(Day23.part2(&board), 20);
+    }
+}
@@ -0,0 +1,136 @@
+use itertools::Itertools;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::{Expected, ParseError};
+use crate::search::{self, astar};
+use crate::solution::Solution;
+
+#[derive(Clone)]
+pub(crate) struct Board {
+    board: Grid<char>,
+    /// The board without its walls, where the blizzards wrap around.
+    valley: Grid<char>,
+    start: Point,
+    end: Point,
+}
+
+impl Board {
+    fn new(content: &str) -> Result<Board, ParseError> {
+        let expected = Expected::OneOf("'.', '#', '^', 'v', '<' or '>'".to_string());
+        let board = Grid::parse(content, |c| c == '.' || c == '#' || Direction::from_arrow(c).is_some(), expected)?;
+        if board.width() < 3 || board.height() < 3 {
+            return Err(ParseError::new("", "", Expected::Format("a valley surrounded by walls".to_string())));
+        }
+        let valley = Grid::from_rows((1..board.height() - 1)
+            .map(|y| board.row(y)[1..board.width() - 1].to_vec())
+            .collect_vec());
+        let start = Point::new(1, 0);
+        let end = Point::new(board.width() as i32 - 2, board.height() as i32 - 1);
+        Ok(Board { board, valley, start, end })
+    }
+
+    fn step(&self, position: Point, vector: Point) -> Option<Point> {
+        let position = position + vector;
+        self.board.get(position)
+            .filter(|&&c| c != '#')
+            .map(|_| position)
+    }
+
+    /// Blizzards move in straight lines, so a blizzard is at `position` after `minute` minutes
+    /// exactly when the matching arrow started `minute` steps behind it.
+    fn will_be_taken(&self, position: Point, minute: usize) -> bool {
+        if position == self.start || position == self.end {
+            return false;
+        }
+        Direction::ALL.iter().any(|direction| {
+            let origin = self.valley.wrap(position - Point::new(1, 1) - direction.vector() * minute as i32);
+            Direction::from_arrow(self.valley[origin]) == Some(*direction)
+        })
+    }
+
+    /// Every minute brings the expedition at most one step closer, so the distance to `end` is a valid heuristic.
+    fn shortest_time(&self, start: Point, end: Point, minute: usize) -> usize {
+        let search = astar(self, [(start, minute)], |(position, _)| *position == end, |(position, _)| position.manhattan(&end) as u64);
+        minute + search.goal_cost().unwrap() as usize
+    }
+}
+
+impl search::Graph for Board {
+    /// Position of the expedition and the minute it gets there.
+    type Node = (Point, usize);
+
+    fn neighbours(&self, (position, minute): &(Point, usize)) -> Vec<((Point, usize), u64)> {
+        Direction::ALL.iter()
+            .map(|direction| direction.vector())
+            .chain([Point::ORIGIN])
+            .filter_map(|vector| self.step(*position, vector))
+            .filter(|next| !self.will_be_taken(*next, minute + 1))
+            .map(|next| ((next, minute + 1), 1))
+            .collect_vec()
+    }
+}
+
+fn part1(board: &Board) -> usize {
+    board.shortest_time(board.start, board.end, 0)
+}
+
+fn part2(board: &Board) -> usize {
+    let there = board.shortest_time(board.start, board.end, 0);
+    let back = board.shortest_time(board.end, board.start, there);
+    board.shortest_time(board.start, board.end, back)
+}
+
+pub(crate) struct Day24;
+
+impl Solution for Day24 {
+    type Input = Board;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, content: &str) -> Result<Board, ParseError> {
+        Board::new(content)
+    }
+
+    fn part1(&self, board: &Board) -> usize {
+        part1(board)
+    }
+
+    fn part2(&self, board: &Board) -> usize {
+        part2(board)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn part1_example() {
+        let board = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(Day24.part1(&board), 18);
+    }
+
+    #[test]
+    fn blizzards_wrap_around_the_valley() {
+        let board = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(board.valley.to_string(), ">>.<^<\n.<..<<\n>v.><>\n<^v^^>");
+        // The blizzard starting in the fourth column of the first row leaves on the left and reaches the right end.
+        assert!(board.will_be_taken(Point::new(6, 1), 4));
+        assert!(!board.will_be_taken(Point::new(6, 1), 2));
+        assert!(Day24.parse("#.\n.#").is_err());
+    }
+
+    #[test]
+    fn part2_example() {
+        let board = Day24.parse(EXAMPLE).unwrap();
+        assert_eq!(Day24.part2(&board), 54);
+    }
+}
@@ -0,0 +1,142 @@
+use itertools::Itertools;
+use crate::parse::{Expected, parse_lines, ParseError};
+use crate::solution::Solution;
+
+pub(crate) struct ElfNumber {
+    value: i64,
+}
+
+fn digit_value(digit: &char) -> Option<i64> {
+    match digit {
+        '2' => Some(2),
+        '1' => Some(1),
+        '0' => Some(0),
+        '-' => Some(-1),
+        '=' => Some(-2),
+        _ => None
+    }
+}
+
+fn value_digit(v: i64) -> char {
+    match v {
+        -2 => '=',
+        -1 => '-',
+        0 => '0',
+        1 => '1',
+        2 => '2',
+        _ => panic!()
+    }
+}
+
+fn multiplier(position: usize) -> i64 {
+    let mut res = 1;
+    for _ in 0..position {
+        res *= 5;
+    }
+    res
+}
+
+impl ElfNumber {
+    fn new(line: &str) -> Result<ElfNumber, ParseError> {
+        let expected = || Expected::Format("a SNAFU number made of digits 2, 1, 0, - and =".to_string());
+        if line.is_empty() {
+            return Err(ParseError::new(line, line, expected()));
+        }
+        let digits = line.char_indices().rev().collect_vec();
+        let mut value = 0;
+        for position in 0..digits.len() {
+            let (index, c) = digits.get(position).unwrap();
+            let digit = digit_value(c).ok_or_else(|| ParseError::new(line, &line[*index..], expected()))?;
+            value += digit * multiplier(position)
+        }
+        Ok(ElfNumber { value })
+    }
+    fn add(&self, other: &ElfNumber) -> ElfNumber {
+        ElfNumber {
+            value: self.value + other.value
+        }
+    }
+}
+
+impl ToString for ElfNumber {
+    fn to_string(&self) -> String {
+        let mut res = vec![];
+        let mut value = self.value;
+        while value > 0 {
+            let x = value + 2;
+            let digit = x.rem_euclid(5) - 2;
+            value = x.div_euclid(5);
+            res.insert(0, value_digit(digit));
+        }
+        res.iter().join("")
+    }
+}
+
+fn part1(numbers: &Vec<ElfNumber>) -> String {
+    let mut result = ElfNumber { value: 0 };
+    for n in numbers {
+        result = result.add(n);
+    }
+    result.to_string()
+}
+
+pub(crate) struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<ElfNumber>;
+    type Output1 = String;
+    type Output2 = &'static str;
+
+    fn parse(&self, content: &str) -> Result<Vec<ElfNumber>, ParseError> {
+        parse_lines(content, ElfNumber::new)
+    }
+
+    fn part1(&self, numbers: &Vec<ElfNumber>) -> String {
+        part1(numbers)
+    }
+
+    fn part2(&self, _numbers: &Vec<ElfNumber>) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+
+    #[test]
+    fn part1_example() {
+        let numbers = Day25.parse(EXAMPLE).unwrap();
+        assert_eq!(Day25.part1(&numbers), "2=-1=0");
+    }
+
+    #[test]
+    fn conversion_round_trip() {
+        for (value, digits) in [(1, "1"), (3, "1="), (2022, "1=11-2"), (12345, "1-0---0"), (314159265, "1121-1110-1=0")] {
+            let number = ElfNumber::new(digits).unwrap();
+            assert_eq!(number.value, value);
+            assert_eq!(number.to_string(), digits);
+        }
+    }
+
+    #[test]
+    fn invalid_digit_is_reported() {
+        let error = Day25.parse("12\n1x0").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+}
@@ -0,0 +1,203 @@
+use std::ops::{BitAnd, BitOr};
+use crate::parse::{Expected, parse_lines, ParseError};
+use crate::solution::{Maybe, parameter, Solution, unknown_parameter};
+
+fn item_score(item: &char) -> i32 {
+    match item {
+        'a'..='z' => (*item as i32) - ('a' as i32) + 1,
+        'A'..='Z' => (*item as i32) - ('A' as i32) + 27,
+        _ => panic!()
+    }
+}
+
+/// Set of item types, bit `n` standing for the item with score `n`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) struct Items(u64);
+
+impl Items {
+    /// Every item type, so intersecting with it changes nothing.
+    const ALL: Items = Items(((1 << 52) - 1) << 1);
+
+    fn new(items: &str) -> Items {
+        Items(items.chars().fold(0, |mask, item| mask | 1 << item_score(&item)))
+    }
+
+    /// Sum of the scores of the item types in the set.
+    fn score(&self) -> i32 {
+        (1..=52).filter(|score| self.0 & 1 << score != 0).sum()
+    }
+}
+
+impl BitAnd for Items {
+    type Output = Items;
+
+    fn bitand(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+}
+
+impl BitOr for Items {
+    type Output = Items;
+
+    fn bitor(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+}
+
+pub(crate) struct Rucksack {
+    compartments: [Items; 2],
+}
+
+impl Rucksack {
+    fn items(&self) -> Items {
+        self.compartments[0] | self.compartments[1]
+    }
+
+    /// Items packed in both compartments.
+    fn common(&self) -> Items {
+        self.compartments[0] & self.compartments[1]
+    }
+}
+
+fn parse_rucksack(line: &str) -> Result<Rucksack, ParseError> {
+    match line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+        Some((index, _)) => Err(ParseError::new(line, &line[index..], Expected::Format("an item letter a-z or A-Z".to_string()))),
+        None if line.len() % 2 == 1 => Err(ParseError::new(line, &line[line.len()..], Expected::Format("an even number of items, half in each compartment".to_string()))),
+        None => {
+            let (left, right) = line.split_at(line.len() / 2);
+            Ok(Rucksack { compartments: [Items::new(left), Items::new(right)] })
+        }
+    }
+}
+
+fn part1(rucksacks: &[Rucksack]) -> i32 {
+    rucksacks.iter()
+        .map(|rucksack| rucksack.common().score())
+        .sum()
+}
+
+/// Items every elf of the group carries.
+fn find_badge(group: &[Rucksack]) -> Items {
+    group.iter()
+        .fold(Items::ALL, |common, rucksack| common & rucksack.items())
+}
+
+/// Sum of the badges of all groups, `None` when the last group is incomplete.
+fn part2(rucksacks: &[Rucksack], group: usize) -> Option<i32> {
+    let groups = rucksacks.chunks_exact(group);
+    if groups.remainder().is_empty() {
+        Some(groups.map(|group| find_badge(group).score()).sum())
+    } else {
+        None
+    }
+}
+
+pub(crate) struct Day3 {
+    /// Elves sharing a badge.
+    pub(crate) group: usize,
+}
+
+impl Default for Day3 {
+    fn default() -> Day3 {
+        Day3 { group: 3 }
+    }
+}
+
+impl Solution for Day3 {
+    type Input = Vec<Rucksack>;
+    type Output1 = i32;
+    type Output2 = Maybe<i32>;
+
+    fn parse(&self, contents: &str) -> Result<Vec<Rucksack>, ParseError> {
+        parse_lines(contents, parse_rucksack)
+    }
+
+    fn part1(&self, rucksacks: &Vec<Rucksack>) -> i32 {
+        part1(rucksacks)
+    }
+
+    fn part2(&self, rucksacks: &Vec<Rucksack>) -> Maybe<i32> {
+        Maybe(part2(rucksacks, self.group))
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "group" => match parameter(key, value)? {
+                0 => return Err("parameter 'group' needs at least one elf".to_string()),
+                group => self.group = group
+            },
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+
+    #[test]
+    fn part1_example() {
+        let rucksacks = Day3::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::default().part1(&rucksacks), 157);
+    }
+
+    #[test]
+    fn part2_example() {
+        let rucksacks = Day3::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day3::default().part2(&rucksacks), Maybe(Some(70)));
+    }
+
+    #[test]
+    fn odd_rucksacks_are_reported() {
+        let error = Day3::default().parse("abcd\nabc\nabcd").err().unwrap();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 4, ""));
+    }
+
+    #[test]
+    fn incomplete_group_has_no_badge() {
+        let day = Day3::default();
+        let rucksacks = day.parse(&format!("{}\nabcd\nefgh", EXAMPLE)).unwrap();
+        assert_eq!(day.part1(&rucksacks), 157);
+        assert_eq!(day.part2(&rucksacks).to_string(), "-");
+        assert_eq!(Day3 { group: 2 }.part2(&rucksacks), Maybe(Some(114 + 136 + 121)));
+    }
+
+    #[test]
+    fn item_scores() {
+        assert_eq!(item_score(&'a'), 1);
+        assert_eq!(item_score(&'z'), 26);
+        assert_eq!(item_score(&'A'), 27);
+        assert_eq!(item_score(&'Z'), 52);
+    }
+
+    #[test]
+    fn item_sets() {
+        let items = Items::new("aZza");
+        assert_eq!(items, Items(1 << 1 | 1 << 26 | 1 << 52));
+        assert_eq!(items.score(), 1 + 26 + 52);
+        assert_eq!(items & Items::new("zb"), Items::new("z"));
+        assert_eq!(Items::ALL, Items::new("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"));
+    }
+
+    #[test]
+    fn other_group_sizes() {
+        let rucksacks = Day3::default().parse(EXAMPLE).unwrap();
+        // A group of one elf shares everything it carries.
+        assert_eq!(find_badge(&rucksacks[..1]).score(), Items::new("vJrwpWtwJgWrhcsFMMfFFhFp").score());
+        assert_eq!(find_badge(&rucksacks[..2]), Items::new("FMfrs"));
+        assert_eq!(part2(&rucksacks, 2), Some(114 + 136 + 121));
+        let mut day = Day3::default();
+        assert!(Solution::configure(&mut day, "group", "0").is_err());
+        Solution::configure(&mut day, "group", "2").unwrap();
+        assert_eq!(day.group, 2);
+    }
+}
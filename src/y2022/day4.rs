@@ -0,0 +1,122 @@
+use crate::interval::Interval;
+use crate::parse::{Expected, number, parse_lines, ParseError, split_once};
+use crate::solution::Solution;
+
+fn parse_range(line: &str, entry: &str) -> Result<Interval, ParseError> {
+    let (x, y) = split_once(line, entry, "-")?;
+    let (start, stop) = (number(line, x)?, number(line, y)?);
+    return if start <= stop {
+        Ok(Interval::new(start, stop))
+    } else {
+        Err(ParseError::new(line, entry, Expected::Format("<start>-<stop> with start not above stop".to_string())))
+    };
+}
+
+pub(crate) struct ElfPair {
+    first: Interval,
+    second: Interval,
+}
+
+impl ElfPair {
+    fn new(first: Interval, second: Interval) -> ElfPair {
+        ElfPair {
+            first,
+            second,
+        }
+    }
+    fn is_fully_overlapped(&self) -> bool {
+        self.first.contains_interval(&self.second) | self.second.contains_interval(&self.first)
+    }
+
+    fn overlaps(&self) -> bool {
+        self.first.overlaps(&self.second)
+    }
+}
+
+fn part1(elfs: &Vec<ElfPair>) -> usize {
+    elfs.iter()
+        .filter(|pair| pair.is_fully_overlapped())
+        .count()
+}
+
+fn part2(elfs: &Vec<ElfPair>) -> usize {
+    elfs.iter()
+        .filter(|pair| pair.overlaps())
+        .count()
+}
+
+
+pub(crate) struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<ElfPair>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, content: &str) -> Result<Vec<ElfPair>, ParseError> {
+        parse_lines(content, |line| {
+            let (elf1, elf2) = split_once(line, line, ",")?;
+            Ok(ElfPair::new(parse_range(line, elf1)?, parse_range(line, elf2)?))
+        })
+    }
+
+    fn part1(&self, elfs: &Vec<ElfPair>) -> usize {
+        part1(elfs)
+    }
+
+    fn part2(&self, elfs: &Vec<ElfPair>) -> usize {
+        part2(elfs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+
+    fn pair(first: (i64, i64), second: (i64, i64)) -> ElfPair {
+        ElfPair::new(Interval::new(first.0, first.1), Interval::new(second.0, second.1))
+    }
+
+    #[test]
+    fn part1_example() {
+        let elfs = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part1(&elfs), 2);
+    }
+
+    #[test]
+    fn part2_example() {
+        let elfs = Day4.parse(EXAMPLE).unwrap();
+        assert_eq!(Day4.part2(&elfs), 4);
+    }
+
+    #[test]
+    fn overlaps() {
+        assert!(pair((2, 4), (3, 5)).overlaps());
+        assert!(pair((2, 4), (4, 8)).overlaps());
+        assert!(pair((2, 4), (2, 2)).overlaps());
+        assert!(pair((3, 5), (2, 4)).overlaps());
+        assert!(!pair((2, 4), (5, 8)).overlaps());
+    }
+
+    #[test]
+    fn fully_contains() {
+        assert!(pair((2, 8), (3, 7)).is_fully_overlapped());
+        assert!(pair((4, 6), (6, 6)).is_fully_overlapped());
+        assert!(pair((4, 6), (4, 6)).is_fully_overlapped());
+        assert!(pair((3, 7), (2, 8)).is_fully_overlapped());
+        assert!(!pair((3, 7), (2, 6)).is_fully_overlapped());
+    }
+
+    #[test]
+    fn reversed_range_is_reported() {
+        assert!(Day4.parse("4-2,1-1").is_err());
+    }
+}
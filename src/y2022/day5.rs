@@ -0,0 +1,168 @@
+use itertools::Itertools;
+use regex::Regex;
+use crate::parse::{Expected, lines, number, ParseError};
+use crate::solution::Solution;
+
+fn parse_entry(chars: &[char]) -> Option<Option<char>> {
+    match chars {
+        ['[', c, ']', ..] => Some(Some(*c)),
+        [' ', ' ', ' ', ..] => Some(None),
+        _ => None
+    }
+}
+
+fn parse_line(line: &str) -> Result<Vec<Option<char>>, ParseError> {
+    let chars = line.chars().collect_vec();
+    let mut entries = vec![];
+    for (index, chunk) in chars.chunks(4).enumerate() {
+        match parse_entry(chunk) {
+            Some(entry) => entries.push(entry),
+            None => {
+                let offset = line.char_indices().nth(index * 4).map(|(i, _)| i).unwrap_or(line.len());
+                return Err(ParseError::new(line, &line[offset..], Expected::Format("a crate like [A] or an empty slot".to_string())));
+            }
+        }
+    }
+    Ok(entries)
+}
+
+fn parse_stacks(content: &str) -> Result<Vec<Vec<Option<char>>>, ParseError> {
+    let lines = lines(content)
+        .collect_vec();
+    let (_, stacks) = lines
+        .split_last()
+        .unwrap();
+    stacks.iter()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|e| e.shifted(index)))
+        .collect()
+}
+
+fn build_stacks(stacks_values: &Vec<Vec<Option<char>>>) -> Vec<Vec<char>> {
+    let stacks_count = stacks_values.iter().map(|s| s.len()).max().unwrap_or(0);
+    let mut stacks: Vec<Vec<char>> = vec![vec![]; stacks_count];
+    for row in stacks_values {
+        for i in 0..row.len() {
+            match row[i] {
+                None => {}
+                Some(val) => stacks[i].insert(0, val)
+            }
+        }
+    }
+    stacks
+}
+
+fn parse_stack_number(line: &str, token: &str, stacks_count: usize) -> Result<usize, ParseError> {
+    match number(line, token)? {
+        stack if (1..=stacks_count).contains(&stack) => Ok(stack),
+        _ => Err(ParseError::new(line, token, Expected::Format(format!("a stack number between 1 and {}", stacks_count))))
+    }
+}
+
+fn parse_command(line: &str, stacks_count: usize) -> Result<(usize, usize, usize), ParseError> {
+    let pattern = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+    let captures = pattern.captures(line)
+        .ok_or_else(|| ParseError::new(line, line, Expected::Format("move <count> from <stack> to <stack>".to_string())))?;
+    let count = number(line, captures.get(1).unwrap().as_str())?;
+    let src = parse_stack_number(line, captures.get(2).unwrap().as_str(), stacks_count)?;
+    let dst = parse_stack_number(line, captures.get(3).unwrap().as_str(), stacks_count)?;
+    Ok((count, src, dst))
+}
+
+fn parse_commands(commands: &str, stacks_count: usize) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    lines(commands)
+        .enumerate()
+        .map(|(index, line)| parse_command(line, stacks_count).map_err(|e| e.shifted(index)))
+        .collect()
+}
+
+fn part1(original_stacks: &Vec<Vec<char>>, commands: &Vec<(usize, usize, usize)>) -> String {
+    let mut stacks = original_stacks.clone();
+    for &(count, src, dst) in commands {
+        for _ in 0..count {
+            let val = stacks[src - 1].pop().unwrap();
+            stacks[dst - 1].push(val);
+        }
+    }
+    stacks.iter()
+        .map(|s| s.last().unwrap())
+        .collect()
+}
+
+fn part2(original_stacks: &Vec<Vec<char>>, commands: &Vec<(usize, usize, usize)>) -> String {
+    let mut stacks = original_stacks.clone();
+    for &(count, src, dst) in commands {
+        let mut popped = vec![];
+        for _ in 0..count {
+            let val = stacks[src - 1].pop().unwrap();
+            popped.insert(0, val);
+        }
+        stacks[dst - 1].extend_from_slice(&mut popped);
+    }
+    stacks.iter()
+        .map(|s| s.last().unwrap())
+        .collect()
+}
+
+pub(crate) struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<Vec<char>>, Vec<(usize, usize, usize)>);
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, content: &str) -> Result<Self::Input, ParseError> {
+        let (stacks_section, commands_section) = content.split_once("\n\n")
+            .ok_or_else(|| ParseError::new("", "", Expected::Format("an empty line between stacks and commands".to_string()))
+                .shifted(lines(content).count()))?;
+        let stacks_values = parse_stacks(stacks_section)?;
+        let stacks = build_stacks(&stacks_values);
+        let commands_offset = lines(stacks_section).count() + 1;
+        let commands = parse_commands(commands_section, stacks.len())
+            .map_err(|e| e.shifted(commands_offset))?;
+        Ok((stacks, commands))
+    }
+
+    fn part1(&self, (stacks, commands): &Self::Input) -> String {
+        part1(stacks, commands)
+    }
+
+    fn part2(&self, (stacks, commands): &Self::Input) -> String {
+        part2(stacks, commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = concat!(
+        "    [D]    \n",
+        "[N] [C]    \n",
+        "[Z] [M] [P]\n",
+        " 1   2   3 \n",
+        "\n",
+        "move 1 from 2 to 1\n",
+        "move 3 from 1 to 3\n",
+        "move 2 from 2 to 1\n",
+        "move 1 from 1 to 2",
+    );
+
+    #[test]
+    fn part1_example() {
+        let input = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part1(&input), "CMZ");
+    }
+
+    #[test]
+    fn part2_example() {
+        let input = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(Day5.part2(&input), "MCD");
+    }
+
+    #[test]
+    fn stacks_are_built_bottom_up() {
+        let (stacks, _) = Day5.parse(EXAMPLE).unwrap();
+        assert_eq!(stacks, vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    }
+}
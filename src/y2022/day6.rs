@@ -0,0 +1,86 @@
+use itertools::Itertools;
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+fn all_unique(window: &[char]) -> bool {
+    window.iter()
+        .unique()
+        .count() == window.len()
+}
+
+fn find_marker(content: &str, block_size: usize) -> usize {
+    let letters = content.chars()
+        .collect_vec();
+    let code: String = letters
+        .windows(block_size)
+        .find_or_first(|window| all_unique(window))
+        .unwrap()
+        .iter()
+        .collect();
+    content.find(&code).unwrap() + block_size
+}
+
+fn part1(content: &str) -> usize {
+    find_marker(content, 4)
+}
+
+fn part2(content: &str) -> usize {
+    find_marker(content, 14)
+}
+
+pub(crate) struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, content: &str) -> Result<String, ParseError> {
+        Ok(content.to_string())
+    }
+
+    fn part1(&self, content: &String) -> usize {
+        part1(content)
+    }
+
+    fn part2(&self, content: &String) -> usize {
+        part2(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::Part;
+    use crate::solution::Puzzle;
+    use super::*;
+
+    const EXAMPLES: [(&str, usize, usize); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwljb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn part1_examples() {
+        for (example, expected, _) in EXAMPLES {
+            let content = Day6.parse(example).unwrap();
+            assert_eq!(Day6.part1(&content), expected, "{}", example);
+        }
+    }
+
+    #[test]
+    fn part2_examples() {
+        for (example, _, expected) in EXAMPLES {
+            let content = Day6.parse(example).unwrap();
+            assert_eq!(Day6.part2(&content), expected, "{}", example);
+        }
+    }
+
+    #[test]
+    fn trailing_newline_is_ignored() {
+        let answers = Day6.run("abcabcabcabcabcabcd\r\n", Part::First).unwrap();
+        assert_eq!(answers.part1.as_deref(), Some("19"));
+    }
+}
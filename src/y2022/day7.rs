@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use itertools::Itertools;
+use crate::parse::{Expected, lines, number, ParseError, split_once};
+use crate::solution::{parameter, Solution, unknown_parameter};
+
+struct File {
+    _name: String,
+    size: i32,
+}
+
+pub(crate) struct Directory {
+    name: String,
+    files: Vec<File>,
+    dirs: Vec<String>,
+}
+
+impl Directory {
+    fn get_size(&self, all_directories: &HashMap<String, Directory>) -> i32 {
+        let files_size: i32 = self.files.iter().map(|f| f.size).sum();
+        let subdirs_size: i32 = self.dirs.iter()
+            .map(|dir_name| (self.name.clone() + "/" + dir_name))
+            .map(|full_path| all_directories.get(full_path.as_str()).unwrap())
+            .map(|d| d.get_size(all_directories))
+            .sum();
+        files_size + subdirs_size
+    }
+}
+
+fn dir_up(current_dir: &String) -> String {
+    let (parent, _) = current_dir.rsplit_once("/").unwrap();
+    parent.to_string()
+}
+
+fn change_dir(command: &str, current_dir: &String) -> String {
+    let (_, target) = command.split_once("cd ").unwrap();
+    return match target {
+        "/" => String::new(),
+        ".." => dir_up(current_dir),
+        dir_name => current_dir.to_string() + "/" + dir_name
+    };
+}
+
+fn parse_file(line: &str) -> Result<File, ParseError> {
+    let (size, name) = split_once(line, line, " ")?;
+    Ok(File { size: number(line, size)?, _name: name.to_string() })
+}
+
+fn parse_listing(current_dir: &str, command: &str) -> Result<Directory, ParseError> {
+    let dirs = lines(command)
+        .skip(1)
+        .filter(|line| line.starts_with("dir"))
+        .map(|line| line[4..].to_string())
+        .collect_vec();
+    let files = lines(command)
+        .enumerate()
+        .skip(1)
+        .filter(|(_, line)| !line.starts_with("dir"))
+        .map(|(index, line)| parse_file(line).map_err(|e| e.shifted(index)))
+        .collect::<Result<Vec<File>, ParseError>>()?;
+    Ok(Directory {
+        name: current_dir.to_string(),
+        files,
+        dirs,
+    })
+}
+
+fn parse_commands(content: &str) -> Result<HashMap<String, Directory>, ParseError> {
+    let mut all_directories = HashMap::new();
+    let mut current_dir: String = String::new();
+    let mut line = 0;
+    for command in content.split("\n$") {
+        if command.starts_with(" ls") {
+            let listing = parse_listing(&current_dir, command).map_err(|e| e.shifted(line))?;
+            all_directories.insert(current_dir.clone(), listing);
+        } else if command.contains(" cd ") {
+            current_dir = change_dir(command, &current_dir);
+        } else {
+            let first_line = lines(command).next().unwrap();
+            return Err(ParseError::new(first_line, first_line, Expected::OneOf("$ cd or $ ls".to_string())).shifted(line));
+        }
+        line += lines(command).count();
+    }
+    Ok(all_directories)
+}
+
+fn part1(all_dirs: &HashMap<String, Directory>, limit: i32) -> i32 {
+    all_dirs.values()
+        .map(|d| d.get_size(&all_dirs))
+        .filter(|size| size <= &limit)
+        .sum()
+}
+
+fn part2(all_dirs: &HashMap<String, Directory>, disk: i32, needed: i32) -> i32 {
+    let unused = disk - all_dirs.get("").unwrap().get_size(all_dirs);
+    let missing = needed - unused;
+    all_dirs.values()
+        .map(|d| d.get_size(&all_dirs))
+        .filter(|size| size >= &missing)
+        .min()
+        .unwrap()
+}
+
+pub(crate) struct Day7 {
+    /// Largest directory counted in part 1.
+    pub(crate) limit: i32,
+    /// Total size of the disk.
+    pub(crate) disk: i32,
+    /// Free space the update needs.
+    pub(crate) needed: i32,
+}
+
+impl Default for Day7 {
+    fn default() -> Day7 {
+        Day7 { limit: 100000, disk: 70000000, needed: 30000000 }
+    }
+}
+
+impl Solution for Day7 {
+    type Input = HashMap<String, Directory>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<HashMap<String, Directory>, ParseError> {
+        parse_commands(content)
+    }
+
+    fn part1(&self, all_directories: &HashMap<String, Directory>) -> i32 {
+        part1(all_directories, self.limit)
+    }
+
+    fn part2(&self, all_directories: &HashMap<String, Directory>) -> i32 {
+        part2(all_directories, self.disk, self.needed)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "limit" => self.limit = parameter(key, value)?,
+            "disk" => self.disk = parameter(key, value)?,
+            "needed" => self.needed = parameter(key, value)?,
+            _ => return Err(unknown_parameter(key))
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+    #[test]
+    fn part1_example() {
+        let all_directories = Day7::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::default().part1(&all_directories), 95437);
+    }
+
+    #[test]
+    fn part2_example() {
+        let all_directories = Day7::default().parse(EXAMPLE).unwrap();
+        assert_eq!(Day7::default().part2(&all_directories), 24933642);
+    }
+
+    #[test]
+    fn smaller_update() {
+        let mut day = Day7::default();
+        day.configure("needed", "21713688").unwrap();
+        assert!(day.configure("size", "1").is_err());
+        assert!(day.configure("disk", "big").is_err());
+        let all_directories = day.parse(EXAMPLE).unwrap();
+        assert_eq!(day.part2(&all_directories), 94853);
+    }
+
+    #[test]
+    fn directory_sizes() {
+        let all_directories = Day7::default().parse(EXAMPLE).unwrap();
+        let size = |name: &str| all_directories.get(name).unwrap().get_size(&all_directories);
+        assert_eq!(size("/a/e"), 584);
+        assert_eq!(size("/a"), 94853);
+        assert_eq!(size("/d"), 24933642);
+        assert_eq!(size(""), 48381165);
+    }
+}
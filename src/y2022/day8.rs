@@ -0,0 +1,114 @@
+use itertools::Itertools;
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::parse::{Expected, ParseError};
+use crate::solution::Solution;
+
+fn lines_of_sight(grid: &Grid<i32>, position: Point) -> Vec<Vec<i32>> {
+    Direction::ALL.iter()
+        .map(|direction| grid.ray(position, direction.vector()).map(|(_, height)| *height).collect_vec())
+        .collect_vec()
+}
+
+fn get_highest_tree_in_each_direction(grid: &Grid<i32>, position: Point) -> Vec<i32> {
+    lines_of_sight(grid, position)
+        .iter()
+        .map(|x| x.iter().max().unwrap_or(&-1).clone())
+        .collect_vec()
+}
+
+fn is_visible(grid: &Grid<i32>, position: Point) -> bool {
+    let tree_height = grid[position];
+    let m = get_highest_tree_in_each_direction(grid, position)
+        .iter()
+        .min()
+        .unwrap()
+        .clone();
+    m < tree_height
+}
+
+fn part1(grid: &Grid<i32>) -> i32 {
+    grid.positions()
+        .filter(|position| is_visible(grid, *position))
+        .count() as i32
+}
+
+fn count_smaller(height: &i32, others: &Vec<i32>) -> i32 {
+    let pos = others.iter()
+        .find_position(|x| *x >= height)
+        .map(|(pos, _)| pos + 1)
+        .unwrap_or(others.len());
+    pos as i32
+}
+
+fn calculate_score(grid: &Grid<i32>, position: Point) -> i32 {
+    let tree_height = &grid[position];
+    lines_of_sight(grid, position)
+        .iter()
+        .map(|x| count_smaller(tree_height, x))
+        .fold(1, |a, b| a * b)
+}
+
+fn part2(grid: &Grid<i32>) -> i32 {
+    grid.positions()
+        .map(|position| calculate_score(grid, position))
+        .max()
+        .unwrap()
+}
+
+fn parse_grid(content: &str) -> Result<Grid<i32>, ParseError> {
+    let grid = Grid::parse(content, |c| c.is_ascii_digit(), Expected::Format("a tree height 0-9".to_string()))?;
+    Ok(grid.map(|c| c.to_digit(10).unwrap() as i32))
+}
+
+pub(crate) struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<i32>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Grid<i32>, ParseError> {
+        parse_grid(content)
+    }
+
+    fn part1(&self, grid: &Grid<i32>) -> i32 {
+        part1(grid)
+    }
+
+    fn part2(&self, grid: &Grid<i32>) -> i32 {
+        part2(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390";
+
+    #[test]
+    fn part1_example() {
+        let grid = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part1(&grid), 21);
+    }
+
+    #[test]
+    fn part2_example() {
+        let grid = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(Day8.part2(&grid), 8);
+    }
+
+    #[test]
+    fn scenic_scores() {
+        let grid = Day8.parse(EXAMPLE).unwrap();
+        assert_eq!(calculate_score(&grid, Point::new(2, 1)), 4);
+        assert_eq!(calculate_score(&grid, Point::new(2, 3)), 8);
+        assert_eq!(calculate_score(&grid, Point::new(0, 0)), 0);
+    }
+}
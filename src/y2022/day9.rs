@@ -0,0 +1,121 @@
+use std::collections::HashSet;
+use itertools::Itertools;
+use crate::geometry::{Direction, Point};
+use crate::parse::{Expected, number, parse_lines, ParseError, split_once};
+use crate::solution::Solution;
+
+fn fix_tail(head: Point, tail: Point) -> Point {
+    return if head.chebyshev(&tail) <= 1 {
+        tail
+    } else {
+        tail + (head - tail).signum()
+    };
+}
+
+fn part2(commands: &Vec<(Direction, i32)>) -> i32 {
+    let mut knots = (0..10).map(|_| Point::ORIGIN).collect_vec();
+    let mut visited = HashSet::new();
+    for (direction, steps) in commands {
+        for _ in 0..*steps {
+            knots[0] += direction.vector();
+            for i in 0..knots.len() - 1 {
+                knots[i + 1] = fix_tail(knots[i], knots[i + 1])
+            }
+            visited.insert(knots[knots.len() - 1]);
+        }
+    }
+    visited.len() as i32
+}
+
+fn part1(commands: &Vec<(Direction, i32)>) -> i32 {
+    let mut head = Point::ORIGIN;
+    let mut tail = Point::ORIGIN;
+    let mut visited = HashSet::new();
+    for (direction, steps) in commands {
+        for _ in 0..*steps {
+            head += direction.vector();
+            tail = fix_tail(head, tail);
+            visited.insert(tail);
+        }
+    }
+    visited.len() as i32
+}
+
+fn parse_command(line: &str) -> Result<(Direction, i32), ParseError> {
+    let (direction, steps) = split_once(line, line, " ")?;
+    let mut symbols = direction.chars();
+    match (symbols.next().and_then(Direction::from_letter), symbols.next()) {
+        (Some(direction), None) => Ok((direction, number(line, steps)?)),
+        _ => Err(ParseError::new(line, direction, Expected::OneOf("U, D, L or R".to_string())))
+    }
+}
+
+pub(crate) struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<(Direction, i32)>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(&self, content: &str) -> Result<Vec<(Direction, i32)>, ParseError> {
+        parse_lines(content, parse_command)
+    }
+
+    fn part1(&self, commands: &Vec<(Direction, i32)>) -> i32 {
+        part1(commands)
+    }
+
+    fn part2(&self, commands: &Vec<(Direction, i32)>) -> i32 {
+        part2(commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2";
+
+    const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20";
+
+    #[test]
+    fn part1_example() {
+        let commands = Day9.parse(EXAMPLE).unwrap();
+        assert_eq!(Day9.part1(&commands), 13);
+    }
+
+    #[test]
+    fn part2_example() {
+        let commands = Day9.parse(EXAMPLE).unwrap();
+        assert_eq!(Day9.part2(&commands), 1);
+    }
+
+    #[test]
+    fn part2_larger_example() {
+        let commands = Day9.parse(LARGER_EXAMPLE).unwrap();
+        assert_eq!(Day9.part2(&commands), 36);
+    }
+
+    #[test]
+    fn tail_follows_head() {
+        assert_eq!(fix_tail(Point::new(1, 1), Point::ORIGIN), Point::ORIGIN);
+        assert_eq!(fix_tail(Point::new(2, 0), Point::ORIGIN), Point::new(1, 0));
+        assert_eq!(fix_tail(Point::new(2, 1), Point::ORIGIN), Point::new(1, 1));
+    }
+}
@@ -0,0 +1,60 @@
+use crate::registry::Year;
+
+mod day1;
+mod day2;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+
+pub(crate) fn year() -> Year {
+    Year {
+        year: 2022,
+        puzzles: vec![
+            (1, Box::new(day1::Day1::default())),
+            (2, Box::new(day2::Day2::default())),
+            (3, Box::new(day3::Day3::default())),
+            (4, Box::new(day4::Day4)),
+            (5, Box::new(day5::Day5)),
+            (6, Box::new(day6::Day6)),
+            (7, Box::new(day7::Day7::default())),
+            (8, Box::new(day8::Day8)),
+            (9, Box::new(day9::Day9)),
+            (10, Box::new(day10::Day10)),
+            (11, Box::new(day11::Day11)),
+            (12, Box::new(day12::Day12)),
+            (13, Box::new(day13::Day13)),
+            (14, Box::new(day14::Day14)),
+            (15, Box::new(day15::Day15::default())),
+            (16, Box::new(day16::Day16::default())),
+            (17, Box::new(day17::Day17::default())),
+            (18, Box::new(day18::Day18)),
+            (19, Box::new(day19::Day19::default())),
+            (20, Box::new(day20::Day20)),
+            (21, Box::new(day21::Day21)),
+            (22, Box::new(day22::Day22::default())),
+            (23, Box::new(day23::Day23)),
+            (24, Box::new(day24::Day24)),
+            (25, Box::new(day25::Day25)),
+        ],
+    }
+}